@@ -0,0 +1,100 @@
+//! Airdrop snapshots in, distribution files out.
+//!
+//! Snapshot CSV (with header): `asset,amount` — one row per pass; row order fixes leaf indices.
+//!
+//! The distribution JSON holds the merkle root for `create_airdrop` and, for every leaf,
+//! the `(index, amount, proof)` a holder needs for `claim_airdrop`. Hashes are hex.
+
+use std::{collections::HashSet, path::Path};
+
+use aeterna_client::merkle::AirdropTree;
+use anyhow::{bail, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Deserialize)]
+struct SnapshotRow {
+    asset: String,
+    amount: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Distribution {
+    pub root: String,
+    pub num_leaves: u32,
+    pub total_amount: u64,
+    pub claims: Vec<Claim>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Claim {
+    pub index: u32,
+    pub asset: String,
+    pub amount: u64,
+    pub proof: Vec<String>,
+}
+
+pub fn load_snapshot(path: &Path) -> Result<Vec<(Pubkey, u64)>> {
+    let rows = csv::Reader::from_path(path)
+        .with_context(|| format!("reading {}", path.display()))?
+        .deserialize()
+        .collect::<Result<Vec<SnapshotRow>, _>>()
+        .with_context(|| format!("parsing {}", path.display()))?;
+
+    let mut seen = HashSet::new();
+    let mut allocations = Vec::with_capacity(rows.len());
+    for row in rows {
+        let asset: Pubkey = row.asset.parse().with_context(|| format!("invalid asset {:?}", row.asset))?;
+        if !seen.insert(asset) {
+            bail!("asset {asset} appears more than once in the snapshot");
+        }
+        allocations.push((asset, row.amount));
+    }
+    ensure!(
+        allocations.len() as u64 <= aeterna::state::Airdrop::MAX_LEAVES as u64,
+        "snapshot has {} rows; an airdrop holds at most {}",
+        allocations.len(),
+        aeterna::state::Airdrop::MAX_LEAVES
+    );
+    Ok(allocations)
+}
+
+pub fn build(allocations: &[(Pubkey, u64)]) -> Result<Distribution> {
+    let tree = AirdropTree::new(allocations).context("snapshot is empty")?;
+    let claims = allocations
+        .iter()
+        .enumerate()
+        .map(|(index, (asset, amount))| Claim {
+            index: index as u32,
+            asset: asset.to_string(),
+            amount: *amount,
+            proof: tree.proof(index as u32).iter().map(to_hex).collect(),
+        })
+        .collect();
+
+    Ok(Distribution {
+        root: to_hex(&tree.root()),
+        num_leaves: tree.num_leaves(),
+        total_amount: allocations.iter().map(|(_, amount)| amount).sum(),
+        claims,
+    })
+}
+
+pub fn load_distribution(path: &Path) -> Result<Distribution> {
+    let raw = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))
+}
+
+pub fn to_hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub fn from_hex(hex: &str) -> Result<[u8; 32]> {
+    ensure!(hex.len() == 64 && hex.is_ascii(), "expected 64 hex characters, got {hex:?}");
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+            .with_context(|| format!("invalid hex {hex:?}"))?;
+    }
+    Ok(hash)
+}
@@ -0,0 +1,294 @@
+//! `aeterna-cli` — organizer tooling for AETERNA events, quests and completions.
+
+mod airdrop_file;
+mod quest_file;
+mod tx;
+
+use std::path::{Path, PathBuf};
+
+use aeterna::state::CompletionRecord;
+use aeterna_client::{accounts, instructions, pda};
+use anchor_lang::Discriminator;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Signer},
+};
+
+use tx::{Mode, Sender};
+
+#[derive(Parser)]
+#[command(name = "aeterna-cli", about = "Organizer tooling for the AETERNA program")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, env = "AETERNA_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Organizer keypair (signs and pays)
+    #[arg(long, env = "AETERNA_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Print base64 unsigned transactions instead of sending them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Fee payer / authority for dry runs (defaults to the keypair's pubkey)
+    #[arg(long, requires = "dry_run")]
+    authority: Option<Pubkey>,
+
+    /// Blockhash for dry runs, for fully offline signing
+    #[arg(long, requires = "dry_run")]
+    blockhash: Option<Hash>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create or inspect events
+    #[command(subcommand)]
+    Event(EventCommand),
+    /// Create quests
+    #[command(subcommand)]
+    Quest(QuestCommand),
+    /// List completions for a quest
+    Completions {
+        event: String,
+        quest: String,
+    },
+    /// Show a soul's on-chain stats and Core attributes
+    Soul {
+        asset: Pubkey,
+    },
+    /// Build and create merkle airdrops
+    #[command(subcommand)]
+    Airdrop(AirdropCommand),
+}
+
+#[derive(Subcommand)]
+enum EventCommand {
+    Create { name: String },
+    Show { name: String },
+}
+
+#[derive(Subcommand)]
+enum QuestCommand {
+    /// Create a single quest
+    Create {
+        event: String,
+        name: String,
+        xp_reward: u64,
+        #[arg(long, default_value_t = 0)]
+        min_wealth_tier: u8,
+    },
+    /// Create every quest listed in a .toml or .csv file
+    BulkCreate { event: String, file: PathBuf },
+}
+
+#[derive(Subcommand)]
+enum AirdropCommand {
+    /// Build the merkle tree and per-pass proofs from an `asset,amount` CSV snapshot (offline)
+    Build { snapshot: PathBuf, out: PathBuf },
+    /// Create the on-chain airdrop from a built distribution file
+    Create {
+        name: String,
+        mint: Pubkey,
+        distribution: PathBuf,
+        /// Minimum soul stage to claim (2 = Ascended)
+        #[arg(long, default_value_t = 2)]
+        min_stage: u8,
+    },
+    /// Reclaim the unclaimed supply into `destination` and close the airdrop to claims
+    Sweep { name: String, destination: Pubkey },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if let Command::Airdrop(AirdropCommand::Build { snapshot, out }) = &cli.command {
+        return build_airdrop(snapshot, out);
+    }
+
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+
+    let keypair_path = expand_home(&cli.keypair);
+    let mode = if cli.dry_run {
+        let fee_payer = match cli.authority {
+            Some(authority) => authority,
+            None => load_keypair(&keypair_path)?.pubkey(),
+        };
+        Mode::DryRun { fee_payer, blockhash: cli.blockhash }
+    } else {
+        Mode::Send(load_keypair(&keypair_path)?)
+    };
+    let sender = Sender { rpc, mode };
+
+    match cli.command {
+        Command::Event(EventCommand::Create { name }) => {
+            quest_file::validate_seed_name("event", &name)?;
+            let ix = instructions::create_event(sender.authority(), name.clone());
+            sender.submit(&format!("create_event {name}"), &[ix])
+        }
+        Command::Event(EventCommand::Show { name }) => show_event(&sender.rpc, &name),
+        Command::Quest(QuestCommand::Create { event, name, xp_reward, min_wealth_tier }) => {
+            quest_file::validate_seed_name("quest", &name)?;
+            let ix = instructions::create_quest(
+                sender.authority(),
+                &event,
+                name.clone(),
+                xp_reward,
+                min_wealth_tier,
+            );
+            sender.submit(&format!("create_quest {name}"), &[ix])
+        }
+        Command::Quest(QuestCommand::BulkCreate { event, file }) => {
+            // The whole file is validated up front; a send failure (e.g. the quest already
+            // exists) is reported and the remaining quests are still attempted.
+            let quests = quest_file::load(&file)?;
+            let mut failed = Vec::new();
+            for quest in &quests {
+                let ix = instructions::create_quest(
+                    sender.authority(),
+                    &event,
+                    quest.name.clone(),
+                    quest.xp_reward,
+                    quest.min_wealth_tier,
+                );
+                if let Err(err) = sender.submit(&format!("create_quest {}", quest.name), &[ix]) {
+                    eprintln!("create_quest {} failed: {err:#}", quest.name);
+                    failed.push(quest.name.as_str());
+                }
+            }
+            println!("{} of {} quests created", quests.len() - failed.len(), quests.len());
+            if !failed.is_empty() {
+                bail!("failed to create: {}", failed.join(", "));
+            }
+            Ok(())
+        }
+        Command::Completions { event, quest } => list_completions(&sender.rpc, &event, &quest),
+        Command::Soul { asset } => show_soul(&sender.rpc, &asset),
+        Command::Airdrop(AirdropCommand::Create { name, mint, distribution, min_stage }) => {
+            let distribution = airdrop_file::load_distribution(&distribution)?;
+            let args = aeterna::AirdropArgs {
+                name: name.clone(),
+                merkle_root: airdrop_file::from_hex(&distribution.root)?,
+                num_leaves: distribution.num_leaves,
+                min_stage,
+            };
+            let ix = instructions::create_airdrop(sender.authority(), mint, args);
+            sender.submit(&format!("create_airdrop {name}"), &[ix])?;
+            println!(
+                "fund vault {} with {} tokens",
+                pda::airdrop_vault(&pda::airdrop(&name).0).0,
+                distribution.total_amount
+            );
+            Ok(())
+        }
+        Command::Airdrop(AirdropCommand::Sweep { name, destination }) => {
+            let ix = instructions::sweep_airdrop(sender.authority(), &name, destination);
+            sender.submit(&format!("sweep_airdrop {name}"), &[ix])
+        }
+        Command::Airdrop(AirdropCommand::Build { .. }) => unreachable!("handled before connecting"),
+    }
+}
+
+fn build_airdrop(snapshot: &Path, out: &Path) -> Result<()> {
+    let allocations = airdrop_file::load_snapshot(snapshot)?;
+    let distribution = airdrop_file::build(&allocations)?;
+    std::fs::write(out, serde_json::to_string_pretty(&distribution)?)
+        .with_context(|| format!("writing {}", out.display()))?;
+
+    println!("root        {}", distribution.root);
+    println!("leaves      {}", distribution.num_leaves);
+    println!("total       {}", distribution.total_amount);
+    println!("wrote       {}", out.display());
+    Ok(())
+}
+
+fn show_event(rpc: &RpcClient, name: &str) -> Result<()> {
+    let address = pda::event(name).0;
+    let data = rpc.get_account_data(&address).with_context(|| format!("fetching event {address}"))?;
+    let event = accounts::event(&data)?;
+
+    println!("Event     {address}");
+    println!("name      {}", event.name);
+    println!("authority {}", event.authority);
+    println!("active    {}", event.active);
+    println!("reentry   {:?} ({}s cooldown)", event.reentry_policy, event.reentry_cooldown);
+    println!("version   {}", event.version);
+    Ok(())
+}
+
+fn list_completions(rpc: &RpcClient, event_name: &str, quest_name: &str) -> Result<()> {
+    let event = pda::event(event_name).0;
+    let quest = pda::quest(&event, quest_name).0;
+
+    // CompletionRecord layout: discriminator (8) | quest (32) | asset (32) | ...
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, CompletionRecord::DISCRIMINATOR.to_vec())),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(8, quest.to_bytes().to_vec())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut records = rpc
+        .get_program_accounts_with_config(&aeterna::ID, config)
+        .context("fetching completion records")?
+        .into_iter()
+        .map(|(_, account)| accounts::completion_record(&account.data))
+        .collect::<Result<Vec<_>, _>>()?;
+    records.sort_by_key(|record| record.completed_at);
+
+    println!("{} completions for quest {quest_name} ({quest})", records.len());
+    for record in records {
+        println!("{}  {}", record.completed_at, record.asset);
+    }
+    Ok(())
+}
+
+fn show_soul(rpc: &RpcClient, asset: &Pubkey) -> Result<()> {
+    let address = pda::soul_stats(asset).0;
+    let data = rpc.get_account_data(&address).with_context(|| format!("fetching soul stats {address}"))?;
+    let stats = accounts::soul_stats(&data)?;
+
+    println!("Soul             {asset}");
+    println!("stats            {address}");
+    println!("xp               {}", stats.xp);
+    println!("points           {}", stats.points);
+    println!("level            {}", stats.level);
+    println!("stage            {}", stats.current_stage);
+    println!("wealth_tier      {}", stats.wealth_tier);
+    println!("trading_volume   {}", stats.trading_volume);
+    println!("quests_completed {}", stats.quests_completed);
+
+    let asset_data = rpc.get_account_data(asset).with_context(|| format!("fetching asset {asset}"))?;
+    println!("attributes:");
+    for attr in accounts::pass_attributes(&asset_data)? {
+        println!("  {} = {}", attr.key, attr.value);
+    }
+    Ok(())
+}
+
+fn load_keypair(path: &str) -> Result<solana_sdk::signature::Keypair> {
+    read_keypair_file(path).map_err(|err| anyhow::anyhow!("reading keypair {path}: {err}"))
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
@@ -0,0 +1,69 @@
+//! Quest definitions for bulk creation, read from TOML or CSV.
+//!
+//! TOML:
+//! ```toml
+//! [[quest]]
+//! name = "Main Stage Check-in"
+//! xp_reward = 50
+//! min_wealth_tier = 0   # optional
+//! ```
+//!
+//! CSV (with header): `name,xp_reward,min_wealth_tier`
+
+use std::path::Path;
+
+use aeterna::state::MAX_SEED_NAME_LEN;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct QuestSpec {
+    pub name: String,
+    pub xp_reward: u64,
+    #[serde(default)]
+    pub min_wealth_tier: u8,
+}
+
+#[derive(Deserialize)]
+struct QuestToml {
+    quest: Vec<QuestSpec>,
+}
+
+pub fn load(path: &Path) -> Result<Vec<QuestSpec>> {
+    let quests = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => {
+            let raw = std::fs::read_to_string(path)
+                .with_context(|| format!("reading {}", path.display()))?;
+            toml::from_str::<QuestToml>(&raw)
+                .with_context(|| format!("parsing {}", path.display()))?
+                .quest
+        }
+        Some("csv") => csv::Reader::from_path(path)
+            .with_context(|| format!("reading {}", path.display()))?
+            .deserialize()
+            .collect::<Result<Vec<QuestSpec>, _>>()
+            .with_context(|| format!("parsing {}", path.display()))?,
+        _ => bail!("unsupported quest file {} (expected .toml or .csv)", path.display()),
+    };
+
+    for (i, quest) in quests.iter().enumerate() {
+        validate_seed_name("quest", &quest.name)?;
+        if quests[..i].iter().any(|other| other.name == quest.name) {
+            bail!("quest {:?} is listed twice (names are PDA seeds, so they must be unique)", quest.name);
+        }
+    }
+
+    Ok(quests)
+}
+
+/// Reject names that cannot be used as a PDA seed before any transaction is built
+pub fn validate_seed_name(kind: &str, name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > MAX_SEED_NAME_LEN {
+        bail!(
+            "{kind} name {:?} must be 1-{} bytes (it is used as a PDA seed)",
+            name,
+            MAX_SEED_NAME_LEN
+        );
+    }
+    Ok(())
+}
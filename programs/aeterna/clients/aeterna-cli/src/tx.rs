@@ -0,0 +1,64 @@
+//! Sending transactions, or printing them unsigned for offline signing.
+
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+pub enum Mode {
+    /// Sign with the local keypair and submit
+    Send(Keypair),
+    /// Print base64 unsigned transactions with `fee_payer` as payer
+    DryRun { fee_payer: Pubkey, blockhash: Option<Hash> },
+}
+
+pub struct Sender {
+    pub rpc: RpcClient,
+    pub mode: Mode,
+}
+
+impl Sender {
+    /// The key that signs as authority/payer for built instructions
+    pub fn authority(&self) -> Pubkey {
+        match &self.mode {
+            Mode::Send(keypair) => keypair.pubkey(),
+            Mode::DryRun { fee_payer, .. } => *fee_payer,
+        }
+    }
+
+    pub fn submit(&self, label: &str, instructions: &[Instruction]) -> Result<()> {
+        match &self.mode {
+            Mode::Send(keypair) => {
+                let blockhash = self.rpc.get_latest_blockhash().context("fetching blockhash")?;
+                let tx = Transaction::new_signed_with_payer(
+                    instructions,
+                    Some(&keypair.pubkey()),
+                    &[keypair],
+                    blockhash,
+                );
+                let signature = self
+                    .rpc
+                    .send_and_confirm_transaction(&tx)
+                    .with_context(|| format!("sending {label}"))?;
+                println!("{label}: {signature}");
+            }
+            Mode::DryRun { fee_payer, blockhash } => {
+                let blockhash = match blockhash {
+                    Some(hash) => *hash,
+                    None => self.rpc.get_latest_blockhash().context("fetching blockhash")?,
+                };
+                let mut tx = Transaction::new_unsigned(Message::new(instructions, Some(fee_payer)));
+                tx.message.recent_blockhash = blockhash;
+                println!("{label}: {}", STANDARD.encode(bincode::serialize(&tx)?));
+            }
+        }
+        Ok(())
+    }
+}
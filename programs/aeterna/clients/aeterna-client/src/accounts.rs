@@ -0,0 +1,244 @@
+//! Decoders for AETERNA program accounts and the Core Attributes plugin of a pass.
+
+use anchor_lang::AccountDeserialize;
+use mpl_core::{types::Attribute, Asset};
+use solana_program::pubkey::Pubkey;
+
+pub use aeterna::state::{
+    Achievement, AchievementClaim, ActionClaim, ActionType, Airdrop, AttendanceRecord, BoostWindow,
+    CompletionRecord, Event, EventLeaderboard, EventParticipation, GrantReceipt, Merchant, ProtocolConfig,
+    PulseLink, Quest, Redemption, Reward, SoulStats, XpTable,
+};
+
+use crate::ClientError;
+
+/// Decode any Anchor account, checking its discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T, ClientError> {
+    Ok(T::try_deserialize(&mut &data[..])?)
+}
+
+pub fn soul_stats(data: &[u8]) -> Result<SoulStats, ClientError> {
+    decode(data)
+}
+
+pub fn event(data: &[u8]) -> Result<Event, ClientError> {
+    decode(data)
+}
+
+pub fn quest(data: &[u8]) -> Result<Quest, ClientError> {
+    decode(data)
+}
+
+pub fn pulse_link(data: &[u8]) -> Result<PulseLink, ClientError> {
+    decode(data)
+}
+
+pub fn completion_record(data: &[u8]) -> Result<CompletionRecord, ClientError> {
+    decode(data)
+}
+
+pub fn event_leaderboard(data: &[u8]) -> Result<EventLeaderboard, ClientError> {
+    decode(data)
+}
+
+pub fn event_participation(data: &[u8]) -> Result<EventParticipation, ClientError> {
+    decode(data)
+}
+
+pub fn protocol_config(data: &[u8]) -> Result<ProtocolConfig, ClientError> {
+    decode(data)
+}
+
+pub fn achievement(data: &[u8]) -> Result<Achievement, ClientError> {
+    decode(data)
+}
+
+pub fn achievement_claim(data: &[u8]) -> Result<AchievementClaim, ClientError> {
+    decode(data)
+}
+
+pub fn attendance_record(data: &[u8]) -> Result<AttendanceRecord, ClientError> {
+    decode(data)
+}
+
+pub fn reward(data: &[u8]) -> Result<Reward, ClientError> {
+    decode(data)
+}
+
+pub fn redemption(data: &[u8]) -> Result<Redemption, ClientError> {
+    decode(data)
+}
+
+pub fn airdrop(data: &[u8]) -> Result<Airdrop, ClientError> {
+    decode(data)
+}
+
+pub fn merchant(data: &[u8]) -> Result<Merchant, ClientError> {
+    decode(data)
+}
+
+pub fn boost_window(data: &[u8]) -> Result<BoostWindow, ClientError> {
+    decode(data)
+}
+
+pub fn grant_receipt(data: &[u8]) -> Result<GrantReceipt, ClientError> {
+    decode(data)
+}
+
+pub fn action_type(data: &[u8]) -> Result<ActionType, ClientError> {
+    decode(data)
+}
+
+pub fn action_claim(data: &[u8]) -> Result<ActionClaim, ClientError> {
+    decode(data)
+}
+
+pub fn xp_table(data: &[u8]) -> Result<XpTable, ClientError> {
+    decode(data)
+}
+
+/// The linked Pulse wallet, or `None` if the pass has changed hands since it was linked
+pub fn active_pulse_wallet(link: &PulseLink, asset_data: &[u8]) -> Result<Option<Pubkey>, ClientError> {
+    let asset = Asset::from_bytes(asset_data)?;
+    Ok(link.is_valid_for(&asset.base.owner).then_some(link.pulse_wallet))
+}
+
+/// Decode the Attributes plugin of a Core asset (an AETERNA Pass).
+/// Returns an empty list if the asset has no Attributes plugin.
+pub fn pass_attributes(asset_data: &[u8]) -> Result<Vec<Attribute>, ClientError> {
+    let asset = Asset::from_bytes(asset_data)?;
+    Ok(asset
+        .plugin_list
+        .attributes
+        .map(|plugin| plugin.attributes.attribute_list)
+        .unwrap_or_default())
+}
+
+/// Look up a single attribute value by key
+pub fn attribute<'a>(attributes: &'a [Attribute], key: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aeterna::state::XpTableEntry;
+    use anchor_lang::AccountSerialize;
+
+    /// Serialize like the runtime does: discriminator + Borsh, zero-padded to the allocated size
+    fn account_bytes<T: AccountSerialize>(account: &T, size: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= size, "serialized {} bytes into a {} byte account", data.len(), size);
+        data.resize(size, 0);
+        data
+    }
+
+    fn sample_soul_stats() -> SoulStats {
+        SoulStats {
+            asset: Pubkey::new_unique(),
+            xp: 1_250,
+            quests_completed: 3,
+            current_stage: 2,
+            trading_volume: 9_000_000,
+            bump: 254,
+            version: SoulStats::VERSION,
+            level: 4,
+            wealth_tier: SoulStats::WEALTH_GOLD,
+            points: 80,
+            xp_window_start: 1_700_000_000,
+            xp_window_granted: 500,
+            reserved: [0; 102],
+        }
+    }
+
+    #[test]
+    fn soul_stats_round_trip() {
+        let stats = sample_soul_stats();
+        let decoded = soul_stats(&account_bytes(&stats, SoulStats::SIZE)).unwrap();
+        assert_eq!(decoded.asset, stats.asset);
+        assert_eq!(decoded.xp, stats.xp);
+        assert_eq!(decoded.quests_completed, stats.quests_completed);
+        assert_eq!(decoded.current_stage, stats.current_stage);
+        assert_eq!(decoded.trading_volume, stats.trading_volume);
+        assert_eq!(decoded.level, stats.level);
+        assert_eq!(decoded.wealth_tier, stats.wealth_tier);
+        assert_eq!(decoded.points, stats.points);
+        assert_eq!(decoded.xp_window_start, stats.xp_window_start);
+        assert_eq!(decoded.xp_window_granted, stats.xp_window_granted);
+    }
+
+    #[test]
+    fn quest_with_max_name_fits() {
+        let quest_account = Quest {
+            event: Pubkey::new_unique(),
+            name: "q".repeat(64),
+            xp_reward: 50,
+            bump: 255,
+            version: Quest::VERSION,
+            min_wealth_tier: SoulStats::WEALTH_DIAMOND,
+            reserved: [0; 63],
+        };
+        let decoded = quest(&account_bytes(&quest_account, Quest::SIZE)).unwrap();
+        assert_eq!(decoded.name, quest_account.name);
+        assert_eq!(decoded.min_wealth_tier, SoulStats::WEALTH_DIAMOND);
+    }
+
+    #[test]
+    fn full_xp_table_fits() {
+        let table = XpTable {
+            entries: (0..XpTable::MAX_ENTRIES as u8)
+                .map(|action| XpTableEntry {
+                    action,
+                    name: "n".repeat(XpTable::MAX_NAME_LEN),
+                    xp_per_action: 100 + action as u64,
+                    max_count: u32::MAX,
+                    volume_weight_bps: XpTable::MAX_VOLUME_WEIGHT_BPS,
+                })
+                .collect(),
+            bump: 253,
+            version: XpTable::VERSION,
+            reserved: [0; 32],
+        };
+        let decoded = xp_table(&account_bytes(&table, XpTable::SIZE)).unwrap();
+        assert_eq!(decoded.entries.len(), XpTable::MAX_ENTRIES);
+        assert_eq!(decoded.entry(5).unwrap().xp_per_action, 105);
+    }
+
+    #[test]
+    fn grant_receipt_round_trip() {
+        let receipt = GrantReceipt {
+            receipt_id: [9; 32],
+            asset: Pubkey::new_unique(),
+            xp_amount: 300,
+            granted_at: 1_700_000_000,
+            bump: 250,
+            version: GrantReceipt::VERSION,
+            action: 2,
+            count: 3,
+            reserved: [0; 27],
+        };
+        let decoded = grant_receipt(&account_bytes(&receipt, GrantReceipt::SIZE)).unwrap();
+        assert_eq!(decoded.receipt_id, receipt.receipt_id);
+        assert_eq!(decoded.asset, receipt.asset);
+        assert_eq!(decoded.xp_amount, receipt.xp_amount);
+        assert_eq!((decoded.action, decoded.count), (2, 3));
+    }
+
+    #[test]
+    fn wrong_discriminator_is_rejected() {
+        let data = account_bytes(&sample_soul_stats(), SoulStats::SIZE);
+        assert!(matches!(event(&data), Err(ClientError::Anchor(_))));
+        assert!(matches!(grant_receipt(&data), Err(ClientError::Anchor(_))));
+    }
+
+    #[test]
+    fn truncated_data_is_rejected() {
+        let data = account_bytes(&sample_soul_stats(), SoulStats::SIZE);
+        assert!(soul_stats(&data[..40]).is_err());
+        assert!(soul_stats(&data[..4]).is_err());
+    }
+}
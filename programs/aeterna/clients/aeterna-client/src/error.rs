@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Errors returned while decoding on-chain data
+#[derive(Debug)]
+pub enum ClientError {
+    /// Anchor discriminator or Borsh layout mismatch
+    Anchor(anchor_lang::error::Error),
+    /// Core asset or plugin data could not be decoded
+    Core(std::io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Anchor(err) => write!(f, "anchor decode error: {err}"),
+            ClientError::Core(err) => write!(f, "core asset decode error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<anchor_lang::error::Error> for ClientError {
+    fn from(err: anchor_lang::error::Error) -> Self {
+        ClientError::Anchor(err)
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(err: std::io::Error) -> Self {
+        ClientError::Core(err)
+    }
+}
@@ -0,0 +1,268 @@
+//! Decoders for the `#[event]` types emitted by the program.
+//!
+//! `emit!` writes `Program data: <base64(discriminator ++ borsh)>` to the transaction
+//! logs; these helpers turn those lines back into typed events.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+pub use aeterna::events::*;
+
+const PROGRAM_DATA: &str = "Program data: ";
+const PROGRAM: &str = "Program ";
+
+/// Every event type the program can emit
+pub enum ProgramEvent {
+    PassMinted(PassMinted),
+    PulseLinked(PulseLinked),
+    PulseUnlinked(PulseUnlinked),
+    XpGranted(XpGranted),
+    PulsePayment(PulsePayment),
+    QuestCompleted(QuestCompleted),
+    SoulEvolved(SoulEvolved),
+    EventCreated(EventCreated),
+    QuestCreated(QuestCreated),
+    LevelUp(LevelUp),
+    WealthTierChanged(WealthTierChanged),
+    AchievementClaimed(AchievementClaimed),
+    AttendanceRecorded(AttendanceRecorded),
+    RewardRedeemed(RewardRedeemed),
+    RedemptionFulfilled(RedemptionFulfilled),
+    AirdropCreated(AirdropCreated),
+    AirdropClaimed(AirdropClaimed),
+    AirdropSwept(AirdropSwept),
+    CheckedIn(CheckedIn),
+    CheckedOut(CheckedOut),
+    MerchantUpdated(MerchantUpdated),
+    XpBoosted(XpBoosted),
+    ActionXpClaimed(ActionXpClaimed),
+    LevelTableUpdated(LevelTableUpdated),
+    WealthTiersUpdated(WealthTiersUpdated),
+    XpCapsUpdated(XpCapsUpdated),
+    XpTableUpdated(XpTableUpdated),
+    AccountMigrated(AccountMigrated),
+    LeaderboardCreated(LeaderboardCreated),
+    BadgeCollectionInitialized(BadgeCollectionInitialized),
+    AchievementCreated(AchievementCreated),
+    AttendanceEnabled(AttendanceEnabled),
+    ReentryPolicyUpdated(ReentryPolicyUpdated),
+    RewardCreated(RewardCreated),
+    RewardUpdated(RewardUpdated),
+    PulseLimitsUpdated(PulseLimitsUpdated),
+    PulseSpent(PulseSpent),
+    PulseWithdrawn(PulseWithdrawn),
+    TreasuryVaultInitialized(TreasuryVaultInitialized),
+    TreasuryWithdrawn(TreasuryWithdrawn),
+    BoostWindowCreated(BoostWindowCreated),
+    BoostWindowClosed(BoostWindowClosed),
+    GrantReceiptClosed(GrantReceiptClosed),
+    ActionTypeUpdated(ActionTypeUpdated),
+    PaymentConfigUpdated(PaymentConfigUpdated),
+}
+
+macro_rules! decode_variants {
+    ($discriminator:expr, $payload:expr, $($variant:ident),* $(,)?) => {
+        $(
+            if $discriminator == $variant::DISCRIMINATOR {
+                return $variant::try_from_slice($payload).ok().map(ProgramEvent::$variant);
+            }
+        )*
+    };
+}
+
+/// Decode raw event bytes (discriminator followed by the Borsh payload)
+pub fn decode(data: &[u8]) -> Option<ProgramEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let (discriminator, payload) = data.split_at(8);
+
+    decode_variants!(
+        discriminator,
+        payload,
+        PassMinted,
+        PulseLinked,
+        PulseUnlinked,
+        XpGranted,
+        PulsePayment,
+        QuestCompleted,
+        SoulEvolved,
+        EventCreated,
+        QuestCreated,
+        LevelUp,
+        WealthTierChanged,
+        AchievementClaimed,
+        AttendanceRecorded,
+        RewardRedeemed,
+        RedemptionFulfilled,
+        AirdropCreated,
+        AirdropClaimed,
+        AirdropSwept,
+        CheckedIn,
+        CheckedOut,
+        MerchantUpdated,
+        XpBoosted,
+        ActionXpClaimed,
+        LevelTableUpdated,
+        WealthTiersUpdated,
+        XpCapsUpdated,
+        XpTableUpdated,
+        AccountMigrated,
+        LeaderboardCreated,
+        BadgeCollectionInitialized,
+        AchievementCreated,
+        AttendanceEnabled,
+        ReentryPolicyUpdated,
+        RewardCreated,
+        RewardUpdated,
+        PulseLimitsUpdated,
+        PulseSpent,
+        PulseWithdrawn,
+        TreasuryVaultInitialized,
+        TreasuryWithdrawn,
+        BoostWindowCreated,
+        BoostWindowClosed,
+        GrantReceiptClosed,
+        ActionTypeUpdated,
+        PaymentConfigUpdated,
+    );
+    None
+}
+
+/// Decode every AETERNA event in a transaction's log messages, in emission order.
+///
+/// `Program data:` lines are only attributed to the program on top of the
+/// `Program <id> invoke [n]` / `Program <id> success` stack, so data logged by any other
+/// program (including one AETERNA invokes, or one that invokes AETERNA) is never decoded
+/// as an AETERNA event, even if its bytes happen to start with a matching discriminator.
+pub fn decode_logs<S: AsRef<str>>(logs: &[S]) -> Vec<ProgramEvent> {
+    let program_id = aeterna::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs.iter().map(AsRef::as_ref) {
+        if let Some(encoded) = line.strip_prefix(PROGRAM_DATA) {
+            if stack.last() == Some(&program_id.as_str()) {
+                events.extend(STANDARD.decode(encoded).ok().and_then(|data| decode(&data)));
+            }
+        } else if let Some(rest) = line.strip_prefix(PROGRAM) {
+            let mut words = rest.split_whitespace();
+            match (words.next(), words.next()) {
+                // `Program log:`, `Program return:`, ... never change the stack
+                (Some(id), _) if id.ends_with(':') => {}
+                (Some(id), Some("invoke")) => stack.push(id),
+                (Some(_), Some("success" | "failed:")) => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+    use solana_program::pubkey::Pubkey;
+
+    const OTHER: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+    fn level_up(asset: Pubkey) -> String {
+        let event = LevelUp { asset, previous_level: 1, new_level: 2, title: "Adept".to_string(), xp: 250 };
+        format!("{PROGRAM_DATA}{}", STANDARD.encode(event.data()))
+    }
+
+    fn assets(events: &[ProgramEvent]) -> Vec<Pubkey> {
+        events
+            .iter()
+            .map(|event| match event {
+                ProgramEvent::LevelUp(e) => e.asset,
+                _ => panic!("unexpected event"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_top_level_aeterna_events() {
+        let asset = Pubkey::new_unique();
+        let id = aeterna::ID.to_string();
+        let logs = vec![
+            format!("Program {id} invoke [1]"),
+            "Program log: Instruction: GrantXp".to_string(),
+            level_up(asset),
+            format!("Program {id} consumed 12000 of 200000 compute units"),
+            format!("Program {id} success"),
+        ];
+        assert_eq!(assets(&decode_logs(&logs)), vec![asset]);
+    }
+
+    #[test]
+    fn ignores_data_logged_by_other_programs() {
+        let logs = vec![
+            format!("Program {OTHER} invoke [1]"),
+            level_up(Pubkey::new_unique()),
+            format!("Program {OTHER} success"),
+        ];
+        assert!(decode_logs(&logs).is_empty());
+    }
+
+    #[test]
+    fn ignores_data_from_programs_aeterna_invokes() {
+        let (inner, outer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let id = aeterna::ID.to_string();
+        let logs = vec![
+            format!("Program {id} invoke [1]"),
+            format!("Program {OTHER} invoke [2]"),
+            level_up(inner),
+            format!("Program {OTHER} success"),
+            level_up(outer),
+            format!("Program {id} success"),
+        ];
+        assert_eq!(assets(&decode_logs(&logs)), vec![outer]);
+    }
+
+    #[test]
+    fn decodes_aeterna_invoked_through_cpi() {
+        let asset = Pubkey::new_unique();
+        let id = aeterna::ID.to_string();
+        let logs = vec![
+            format!("Program {OTHER} invoke [1]"),
+            format!("Program {id} invoke [2]"),
+            level_up(asset),
+            format!("Program {id} success"),
+            level_up(Pubkey::new_unique()),
+            format!("Program {OTHER} success"),
+        ];
+        assert_eq!(assets(&decode_logs(&logs)), vec![asset]);
+    }
+
+    #[test]
+    fn program_log_lines_do_not_move_the_stack() {
+        let asset = Pubkey::new_unique();
+        let id = aeterna::ID.to_string();
+        let logs = vec![
+            format!("Program {id} invoke [1]"),
+            "Program log: invoke [2]".to_string(),
+            "Program log: success".to_string(),
+            format!("Program return: {id} AQ=="),
+            level_up(asset),
+            format!("Program {id} success"),
+        ];
+        assert_eq!(assets(&decode_logs(&logs)), vec![asset]);
+    }
+
+    #[test]
+    fn skips_malformed_and_unknown_data() {
+        let id = aeterna::ID.to_string();
+        let logs = vec![
+            format!("Program {id} invoke [1]"),
+            format!("{PROGRAM_DATA}not base64!"),
+            format!("{PROGRAM_DATA}{}", STANDARD.encode([0u8; 16])),
+            format!("{PROGRAM_DATA}{}", STANDARD.encode([1u8; 4])),
+            format!("Program {id} success"),
+        ];
+        assert!(decode_logs(&logs).is_empty());
+    }
+}
@@ -0,0 +1,819 @@
+//! Instruction builders for every AETERNA entrypoint.
+//!
+//! Account lists come from the Anchor-generated `aeterna::accounts` structs and
+//! instruction data from `aeterna::instruction`, so encoding always matches the program.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+use aeterna::{
+    AchievementArgs, ActionTypeArgs, AirdropArgs, AttendanceArgs, BadgeCollectionArgs, BoostWindowArgs,
+    ClaimAirdropArgs, CreateMerchantArgs, EvolveArgs, GrantXpArgs, InitArgs, LevelTier, PaymentConfigArgs, PulseArgs,
+    PulseLimitsArgs, ReentryPolicy, RewardArgs, UpdateActionTypeArgs, UpdateMerchantArgs, UpdateRewardArgs, WealthTierArgs,
+    XpCapArgs, XpTableEntry,
+};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: aeterna::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Mint a pass for `owner` at `event_name`. `asset` must be a fresh keypair that co-signs.
+pub fn initialize_pass(
+    owner: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+    event_name: &str,
+    args: InitArgs,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::InitializePass {
+            signer: owner,
+            authority,
+            payer,
+            asset,
+            collection,
+            mpl_core_program: mpl_core::ID,
+            event,
+            soul_stats: pda::soul_stats(&asset).0,
+            participation: pda::participation(&event, &asset).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::InitializePass { args },
+    )
+}
+
+/// Link a Pulse wallet to `asset`, signed by the asset owner
+pub fn register_pulse_wallet(owner: Pubkey, asset: Pubkey, pulse_wallet: Pubkey) -> Instruction {
+    build(
+        aeterna::accounts::ManagePulse {
+            owner,
+            asset,
+            pulse_link: pda::pulse_link(&asset).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::RegisterPulseWallet {
+            args: PulseArgs { pulse_wallet },
+        },
+    )
+}
+
+/// Close `asset`'s Pulse link, signed by the current owner or the owner who linked it.
+/// `link_owner` is the link's recorded owner, who gets the rent back.
+pub fn unregister_pulse_wallet(owner: Pubkey, asset: Pubkey, link_owner: Pubkey) -> Instruction {
+    build(
+        aeterna::accounts::UnregisterPulse {
+            owner,
+            asset,
+            pulse_link: pda::pulse_link(&asset).0,
+            rent_recipient: link_owner,
+        },
+        aeterna::instruction::UnregisterPulseWallet {},
+    )
+}
+
+pub fn set_pulse_limits(owner: Pubkey, asset: Pubkey, args: PulseLimitsArgs) -> Instruction {
+    build(
+        aeterna::accounts::SetPulseLimits {
+            owner,
+            asset,
+            pulse_link: pda::pulse_link(&asset).0,
+        },
+        aeterna::instruction::SetPulseLimits { args },
+    )
+}
+
+/// Spend `amount` of `mint` from the Pulse vault of `asset`'s link owner `owner`,
+/// signed by the linked Pulse wallet
+pub fn pulse_spend(
+    pulse_wallet: Pubkey,
+    asset: Pubkey,
+    owner: Pubkey,
+    mint: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        aeterna::accounts::PulseSpend {
+            pulse_wallet,
+            asset,
+            pulse_link: pda::pulse_link(&asset).0,
+            vault_authority: pda::pulse_vault_authority(&asset, &owner).0,
+            vault: pda::pulse_vault(&asset, &owner, &mint),
+            destination,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::PulseSpend { amount },
+    )
+}
+
+pub fn pulse_withdraw(owner: Pubkey, asset: Pubkey, mint: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    build(
+        aeterna::accounts::PulseWithdraw {
+            owner,
+            asset,
+            vault_authority: pda::pulse_vault_authority(&asset, &owner).0,
+            vault: pda::pulse_vault(&asset, &owner, &mint),
+            destination,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::PulseWithdraw { amount },
+    )
+}
+
+/// Pay `merchant` from the Pulse vault of `asset`'s link owner `owner` and credit the soul.
+/// `payout` and `mint` are the merchant's `payout` and `mint`; `boost` is a boost window at
+/// the merchant's event.
+#[allow(clippy::too_many_arguments)]
+pub fn pulse_pay(
+    pulse_wallet: Pubkey,
+    asset: Pubkey,
+    owner: Pubkey,
+    merchant: Pubkey,
+    mint: Pubkey,
+    payout: Pubkey,
+    boost: Option<Pubkey>,
+    amount: u64,
+) -> Instruction {
+    build(
+        aeterna::accounts::PulsePay {
+            pulse_wallet,
+            asset,
+            pulse_link: pda::pulse_link(&asset).0,
+            soul_stats: pda::soul_stats(&asset).0,
+            config: pda::config().0,
+            merchant,
+            mint,
+            vault_authority: pda::pulse_vault_authority(&asset, &owner).0,
+            vault: pda::pulse_vault(&asset, &owner, &mint),
+            destination: payout,
+            treasury_vault: pda::treasury_vault(&mint).0,
+            boost,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::PulsePay { amount },
+    )
+}
+
+pub fn evolve_soul(authority: Pubkey, payer: Pubkey, asset: Pubkey, args: EvolveArgs) -> Instruction {
+    build(
+        aeterna::accounts::EvolveSoul {
+            authority,
+            payer,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            config: pda::config().0,
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::EvolveSoul { args },
+    )
+}
+
+pub fn create_event(authority: Pubkey, name: String) -> Instruction {
+    build(
+        aeterna::accounts::CreateEvent {
+            authority,
+            event: pda::event(&name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateEvent { name },
+    )
+}
+
+pub fn create_quest(
+    authority: Pubkey,
+    event_name: &str,
+    name: String,
+    xp_reward: u64,
+    min_wealth_tier: u8,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CreateQuest {
+            authority,
+            event,
+            quest: pda::quest(&event, &name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateQuest {
+            name,
+            xp_reward,
+            min_wealth_tier,
+        },
+    )
+}
+
+pub fn create_leaderboard(authority: Pubkey, event_name: &str) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CreateLeaderboard {
+            authority,
+            event,
+            leaderboard: pda::leaderboard(&event).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateLeaderboard {},
+    )
+}
+
+/// Scanner-signed quest completion for `asset`, owned by `recipient`. The event's leaderboard
+/// address is always passed and updated once it exists; set `boost` to the name of a boost
+/// window at the event.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_quest(
+    authority: Pubkey,
+    payer: Pubkey,
+    event_name: &str,
+    quest_name: &str,
+    asset: Pubkey,
+    recipient: Pubkey,
+    boost: Option<&str>,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    let quest = pda::quest(&event, quest_name).0;
+    build(
+        aeterna::accounts::CompleteQuest {
+            authority,
+            payer,
+            quest,
+            event,
+            asset,
+            recipient,
+            soul_stats: pda::soul_stats(&asset).0,
+            config: pda::config().0,
+            completion_record: pda::completion(&quest, &asset).0,
+            participation: pda::participation(&event, &asset).0,
+            leaderboard: pda::leaderboard(&event).0,
+            boost: boost.map(|name| pda::boost_window(&event, name).0),
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CompleteQuest {},
+    )
+}
+
+/// Backend XP grant. Pass `event` to also credit the soul's participation at that event and
+/// its leaderboard once one exists, and `merchant` to add XP for
+/// `add_trading_volume` at that merchant's rate, multiplied by `boost` if that window is live.
+pub fn grant_xp(
+    authority: Pubkey,
+    asset: Pubkey,
+    event: Option<Pubkey>,
+    merchant: Option<Pubkey>,
+    boost: Option<Pubkey>,
+    args: GrantXpArgs,
+) -> Instruction {
+    build(
+        aeterna::accounts::GrantXp {
+            authority,
+            soul_stats: pda::soul_stats(&asset).0,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+            asset,
+            mpl_core_program: mpl_core::ID,
+            participation: event.map(|event| pda::participation(&event, &asset).0),
+            leaderboard: event.map(|event| pda::leaderboard(&event).0),
+            merchant,
+            boost,
+            receipt: pda::grant_receipt(&args.receipt_id).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::GrantXp { args },
+    )
+}
+
+/// Idempotency key for `GrantXpArgs::receipt_id`: the hash of the off-chain transfer signature
+pub fn receipt_id(signature: &[u8]) -> [u8; 32] {
+    solana_program::hash::hash(signature).to_bytes()
+}
+
+/// Reclaim a `grant_xp` receipt's rent once it is past `GrantReceipt::RETENTION_SECS`
+pub fn close_grant_receipt(authority: Pubkey, receipt_id: &[u8; 32]) -> Instruction {
+    build(
+        aeterna::accounts::CloseGrantReceipt {
+            authority,
+            receipt: pda::grant_receipt(receipt_id).0,
+        },
+        aeterna::instruction::CloseGrantReceipt {},
+    )
+}
+
+pub fn initialize_config(authority: Pubkey, levels: Vec<LevelTier>) -> Instruction {
+    build(
+        aeterna::accounts::InitializeConfig {
+            authority,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::InitializeConfig { levels },
+    )
+}
+
+pub fn update_level_table(authority: Pubkey, levels: Vec<LevelTier>) -> Instruction {
+    build(
+        aeterna::accounts::UpdateConfig {
+            authority,
+            config: pda::config().0,
+        },
+        aeterna::instruction::UpdateLevelTable { levels },
+    )
+}
+
+pub fn update_wealth_tiers(authority: Pubkey, args: WealthTierArgs) -> Instruction {
+    build(
+        aeterna::accounts::UpdateConfig {
+            authority,
+            config: pda::config().0,
+        },
+        aeterna::instruction::UpdateWealthTiers { args },
+    )
+}
+
+pub fn update_xp_caps(authority: Pubkey, args: XpCapArgs) -> Instruction {
+    build(
+        aeterna::accounts::UpdateConfig {
+            authority,
+            config: pda::config().0,
+        },
+        aeterna::instruction::UpdateXpCaps { args },
+    )
+}
+
+pub fn update_payment_config(authority: Pubkey, args: PaymentConfigArgs) -> Instruction {
+    build(
+        aeterna::accounts::UpdateConfig {
+            authority,
+            config: pda::config().0,
+        },
+        aeterna::instruction::UpdatePaymentConfig { args },
+    )
+}
+
+pub fn initialize_xp_table(authority: Pubkey, entries: Vec<XpTableEntry>) -> Instruction {
+    build(
+        aeterna::accounts::InitializeXpTable {
+            authority,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::InitializeXpTable { entries },
+    )
+}
+
+pub fn update_xp_table(authority: Pubkey, entries: Vec<XpTableEntry>) -> Instruction {
+    build(
+        aeterna::accounts::UpdateXpTable {
+            authority,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+        },
+        aeterna::instruction::UpdateXpTable { entries },
+    )
+}
+
+/// Upgrade any legacy-layout AETERNA account in place
+pub fn migrate_account(payer: Pubkey, target: Pubkey) -> Instruction {
+    build(
+        aeterna::accounts::MigrateAccount {
+            payer,
+            target,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::MigrateAccount {},
+    )
+}
+
+/// Create the badge collection. `collection` must be a fresh keypair that co-signs.
+pub fn initialize_badge_collection(authority: Pubkey, collection: Pubkey, args: BadgeCollectionArgs) -> Instruction {
+    build(
+        aeterna::accounts::InitializeBadgeCollection {
+            authority,
+            config: pda::config().0,
+            collection,
+            badge_authority: pda::badge_authority().0,
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::InitializeBadgeCollection { args },
+    )
+}
+
+pub fn create_achievement(authority: Pubkey, args: AchievementArgs) -> Instruction {
+    build(
+        aeterna::accounts::CreateAchievement {
+            authority,
+            config: pda::config().0,
+            achievement: pda::achievement(&args.name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateAchievement { args },
+    )
+}
+
+/// Claim `achievement_name` for `asset`, signed by the pass owner. `badge` must be a fresh
+/// keypair that co-signs; pass `event` for event-scoped achievements.
+pub fn claim_achievement(
+    owner: Pubkey,
+    asset: Pubkey,
+    achievement_name: &str,
+    event: Option<Pubkey>,
+    badge: Pubkey,
+    badge_collection: Pubkey,
+) -> Instruction {
+    let achievement = pda::achievement(achievement_name).0;
+    build(
+        aeterna::accounts::ClaimAchievement {
+            owner,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            achievement,
+            participation: event.map(|event| pda::participation(&event, &asset).0),
+            claim: pda::achievement_claim(&achievement, &asset).0,
+            config: pda::config().0,
+            badge,
+            badge_collection,
+            badge_authority: pda::badge_authority().0,
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::ClaimAchievement {},
+    )
+}
+
+/// Enable attendance proofs for `event_name`. `collection` must be a fresh keypair that co-signs.
+pub fn enable_attendance(authority: Pubkey, event_name: &str, collection: Pubkey, args: AttendanceArgs) -> Instruction {
+    build(
+        aeterna::accounts::EnableAttendance {
+            authority,
+            event: pda::event(event_name).0,
+            collection,
+            attendance_authority: pda::attendance_authority().0,
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::EnableAttendance { args },
+    )
+}
+
+/// Mint the attendance proof for `asset`, owned by `owner`, without a gate scan. `proof` must
+/// be a fresh keypair that co-signs; `attendance_collection` is the event's `attendance_collection`.
+pub fn record_attendance(
+    authority: Pubkey,
+    payer: Pubkey,
+    event_name: &str,
+    asset: Pubkey,
+    owner: Pubkey,
+    proof: Pubkey,
+    attendance_collection: Pubkey,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::RecordAttendance {
+            authority,
+            payer,
+            event,
+            asset,
+            owner,
+            participation: pda::participation(&event, &asset).0,
+            attendance: pda::attendance(&event, &asset).0,
+            proof,
+            attendance_collection,
+            attendance_authority: pda::attendance_authority().0,
+            mpl_core_program: mpl_core::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::RecordAttendance {},
+    )
+}
+
+pub fn create_reward(authority: Pubkey, event_name: &str, args: RewardArgs) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CreateReward {
+            authority,
+            event,
+            reward: pda::reward(&event, &args.name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateReward { args },
+    )
+}
+
+pub fn update_reward(authority: Pubkey, event_name: &str, reward_name: &str, args: UpdateRewardArgs) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::UpdateReward {
+            authority,
+            event,
+            reward: pda::reward(&event, reward_name).0,
+        },
+        aeterna::instruction::UpdateReward { args },
+    )
+}
+
+/// Redeem `reward_name` with `asset`'s points. `serial` is the reward's current `redeemed` count.
+pub fn redeem_reward(owner: Pubkey, asset: Pubkey, event_name: &str, reward_name: &str, serial: u32) -> Instruction {
+    let event = pda::event(event_name).0;
+    let reward = pda::reward(&event, reward_name).0;
+    build(
+        aeterna::accounts::RedeemReward {
+            owner,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            event,
+            reward,
+            redemption: pda::redemption(&reward, serial).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::RedeemReward {},
+    )
+}
+
+pub fn fulfill_redemption(authority: Pubkey, event_name: &str, reward_name: &str, serial: u32) -> Instruction {
+    let event = pda::event(event_name).0;
+    let reward = pda::reward(&event, reward_name).0;
+    build(
+        aeterna::accounts::FulfillRedemption {
+            authority,
+            event,
+            reward,
+            redemption: pda::redemption(&reward, serial).0,
+        },
+        aeterna::instruction::FulfillRedemption {},
+    )
+}
+
+/// Create an airdrop of `mint` tokens; fund the returned vault (`pda::airdrop_vault`) afterwards
+pub fn create_airdrop(authority: Pubkey, mint: Pubkey, args: AirdropArgs) -> Instruction {
+    let airdrop = pda::airdrop(&args.name).0;
+    build(
+        aeterna::accounts::CreateAirdrop {
+            authority,
+            config: pda::config().0,
+            airdrop,
+            mint,
+            vault: pda::airdrop_vault(&airdrop).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateAirdrop { args },
+    )
+}
+
+/// Claim `asset`'s allocation into `destination`, a token account for the airdrop mint
+pub fn claim_airdrop(
+    owner: Pubkey,
+    asset: Pubkey,
+    airdrop_name: &str,
+    destination: Pubkey,
+    args: ClaimAirdropArgs,
+) -> Instruction {
+    let airdrop = pda::airdrop(airdrop_name).0;
+    build(
+        aeterna::accounts::ClaimAirdrop {
+            owner,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            airdrop,
+            vault: pda::airdrop_vault(&airdrop).0,
+            destination,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::ClaimAirdrop { args },
+    )
+}
+
+/// Move an airdrop's unclaimed tokens to `destination` and close it to claims (admin only)
+pub fn sweep_airdrop(authority: Pubkey, airdrop_name: &str, destination: Pubkey) -> Instruction {
+    let airdrop = pda::airdrop(airdrop_name).0;
+    build(
+        aeterna::accounts::SweepAirdrop {
+            authority,
+            config: pda::config().0,
+            airdrop,
+            vault: pda::airdrop_vault(&airdrop).0,
+            destination,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::SweepAirdrop {},
+    )
+}
+
+pub fn set_reentry_policy(authority: Pubkey, event_name: &str, policy: ReentryPolicy, cooldown: u32) -> Instruction {
+    build(
+        aeterna::accounts::SetReentryPolicy {
+            authority,
+            event: pda::event(event_name).0,
+        },
+        aeterna::instruction::SetReentryPolicy { policy, cooldown },
+    )
+}
+
+/// Accounts for the attendance proof a first `check_in` mints
+pub struct AttendanceProof {
+    /// The pass owner, who receives the proof
+    pub owner: Pubkey,
+    /// Fresh keypair that co-signs
+    pub proof: Pubkey,
+    /// The event's `attendance_collection`
+    pub attendance_collection: Pubkey,
+}
+
+/// Scanner-signed gate entry for `asset`. `attendance` is required on the soul's first entry
+/// at an event with attendance proofs enabled, and may be `None` otherwise.
+pub fn check_in(
+    authority: Pubkey,
+    payer: Pubkey,
+    event_name: &str,
+    asset: Pubkey,
+    attendance: Option<AttendanceProof>,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    let proof = attendance.as_ref();
+    build(
+        aeterna::accounts::CheckIn {
+            authority,
+            payer,
+            event,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            participation: pda::participation(&event, &asset).0,
+            owner: proof.map(|p| p.owner),
+            attendance: proof.map(|_| pda::attendance(&event, &asset).0),
+            proof: proof.map(|p| p.proof),
+            attendance_collection: proof.map(|p| p.attendance_collection),
+            attendance_authority: proof.map(|_| pda::attendance_authority().0),
+            mpl_core_program: proof.map(|_| mpl_core::ID),
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CheckIn {},
+    )
+}
+
+/// Scanner-signed gate exit for `asset`
+pub fn check_out(authority: Pubkey, event_name: &str, asset: Pubkey) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CheckOut {
+            authority,
+            event,
+            participation: pda::participation(&event, &asset).0,
+        },
+        aeterna::instruction::CheckOut {},
+    )
+}
+
+/// Register `name` at `event_name`, paying out to `payout` (a token account for the accepted mint)
+pub fn create_merchant(
+    authority: Pubkey,
+    event_name: &str,
+    payout: Pubkey,
+    mint: Pubkey,
+    args: CreateMerchantArgs,
+) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CreateMerchant {
+            authority,
+            event,
+            config: pda::config().0,
+            merchant: pda::merchant(&event, &args.name).0,
+            payout,
+            mint,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateMerchant { args },
+    )
+}
+
+pub fn update_merchant(authority: Pubkey, event_name: &str, name: &str, args: UpdateMerchantArgs) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::UpdateMerchant {
+            authority,
+            event,
+            config: pda::config().0,
+            merchant: pda::merchant(&event, name).0,
+        },
+        aeterna::instruction::UpdateMerchant { args },
+    )
+}
+
+pub fn set_merchant_fee(authority: Pubkey, merchant: Pubkey, fee_bps: u16) -> Instruction {
+    build(
+        aeterna::accounts::SetMerchantFee {
+            authority,
+            config: pda::config().0,
+            merchant,
+        },
+        aeterna::instruction::SetMerchantFee { fee_bps },
+    )
+}
+
+pub fn initialize_treasury_vault(authority: Pubkey, mint: Pubkey) -> Instruction {
+    build(
+        aeterna::accounts::InitializeTreasuryVault {
+            authority,
+            config: pda::config().0,
+            mint,
+            treasury_vault: pda::treasury_vault(&mint).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::InitializeTreasuryVault {},
+    )
+}
+
+pub fn withdraw_treasury(authority: Pubkey, mint: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    build(
+        aeterna::accounts::WithdrawTreasury {
+            authority,
+            config: pda::config().0,
+            treasury_vault: pda::treasury_vault(&mint).0,
+            destination,
+            token_program: anchor_spl::token::ID,
+        },
+        aeterna::instruction::WithdrawTreasury { amount },
+    )
+}
+
+pub fn create_boost_window(authority: Pubkey, event_name: &str, args: BoostWindowArgs) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CreateBoostWindow {
+            authority,
+            event,
+            boost: pda::boost_window(&event, &args.name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateBoostWindow { args },
+    )
+}
+
+pub fn close_boost_window(authority: Pubkey, event_name: &str, name: &str) -> Instruction {
+    let event = pda::event(event_name).0;
+    build(
+        aeterna::accounts::CloseBoostWindow {
+            authority,
+            event,
+            boost: pda::boost_window(&event, name).0,
+        },
+        aeterna::instruction::CloseBoostWindow {},
+    )
+}
+
+/// Owner-signed XP claim for the `action_name` action. Must come right after an instruction
+/// to one of the action's programs that `owner` signs, and only once per transaction.
+pub fn claim_action_xp(owner: Pubkey, asset: Pubkey, action_name: &str) -> Instruction {
+    let action = pda::action_type(action_name).0;
+    build(
+        aeterna::accounts::ClaimActionXp {
+            owner,
+            asset,
+            soul_stats: pda::soul_stats(&asset).0,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+            action,
+            action_claim: pda::action_claim(&action, &asset).0,
+            instructions: solana_program::sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::ClaimActionXp {},
+    )
+}
+
+pub fn create_action_type(authority: Pubkey, args: ActionTypeArgs) -> Instruction {
+    build(
+        aeterna::accounts::CreateActionType {
+            authority,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+            action: pda::action_type(&args.name).0,
+            system_program: system_program::ID,
+        },
+        aeterna::instruction::CreateActionType { args },
+    )
+}
+
+pub fn update_action_type(authority: Pubkey, name: &str, args: UpdateActionTypeArgs) -> Instruction {
+    build(
+        aeterna::accounts::UpdateActionType {
+            authority,
+            config: pda::config().0,
+            xp_table: pda::xp_table().0,
+            action: pda::action_type(name).0,
+        },
+        aeterna::instruction::UpdateActionType { args },
+    )
+}
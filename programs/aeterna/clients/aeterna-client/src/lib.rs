@@ -0,0 +1,17 @@
+//! Rust client SDK for the AETERNA program.
+//!
+//! - [`pda`]: seed derivation for every program-owned account
+//! - [`instructions`]: typed builders for every entrypoint in `aeterna::aeterna`
+//! - [`accounts`]: decoders for program accounts and the Core Attributes plugin of a pass
+//! - [`events`]: decoders for `#[event]` logs emitted by the program
+//! - [`merkle`]: airdrop tree builder matching the program's proof verification
+
+pub mod accounts;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod merkle;
+pub mod pda;
+
+pub use aeterna::ID as PROGRAM_ID;
+pub use error::ClientError;
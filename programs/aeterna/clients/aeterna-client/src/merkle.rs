@@ -0,0 +1,135 @@
+//! Off-chain merkle tree builder for `create_airdrop` / `claim_airdrop`.
+//!
+//! Hashing is shared with the program via `aeterna::merkle`, so roots and proofs built
+//! here verify on-chain. A node without a sibling is carried up unchanged.
+
+use solana_program::pubkey::Pubkey;
+
+use aeterna::merkle::{hash_pair, leaf};
+
+pub struct AirdropTree {
+    /// `layers[0]` are the leaves; the last layer holds only the root
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl AirdropTree {
+    /// Build a tree over `(asset, amount)` allocations; leaf indices follow slice order.
+    /// Returns `None` if `allocations` is empty.
+    pub fn new(allocations: &[(Pubkey, u64)]) -> Option<Self> {
+        if allocations.is_empty() {
+            return None;
+        }
+
+        let leaves = allocations
+            .iter()
+            .enumerate()
+            .map(|(index, (asset, amount))| leaf(index as u32, asset, *amount))
+            .collect();
+
+        let mut layers: Vec<Vec<[u8; 32]>> = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Some(Self { layers })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    pub fn num_leaves(&self) -> u32 {
+        self.layers[0].len() as u32
+    }
+
+    /// Sibling hashes from leaf `index` up to the root
+    pub fn proof(&self, index: u32) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        let mut position = index as usize;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aeterna::merkle::verify;
+
+    fn allocations(count: usize) -> Vec<(Pubkey, u64)> {
+        (0..count).map(|i| (Pubkey::new_unique(), 1_000 + i as u64)).collect()
+    }
+
+    fn assert_all_verify(allocations: &[(Pubkey, u64)]) {
+        let tree = AirdropTree::new(allocations).unwrap();
+        for (index, (asset, amount)) in allocations.iter().enumerate() {
+            let index = index as u32;
+            assert!(
+                verify(&tree.proof(index), &tree.root(), leaf(index, asset, *amount)),
+                "leaf {index} of {} does not verify",
+                allocations.len()
+            );
+        }
+    }
+
+    #[test]
+    fn empty_allocations_have_no_tree() {
+        assert!(AirdropTree::new(&[]).is_none());
+    }
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        let allocations = allocations(1);
+        let tree = AirdropTree::new(&allocations).unwrap();
+        let (asset, amount) = allocations[0];
+        assert_eq!(tree.root(), leaf(0, &asset, amount));
+        assert!(tree.proof(0).is_empty());
+        assert_all_verify(&allocations);
+    }
+
+    #[test]
+    fn every_leaf_verifies_on_chain() {
+        // Odd counts leave a node without a sibling, which is carried up unchanged
+        for count in [2, 3, 4, 5, 7, 8, 9, 33] {
+            let allocations = allocations(count);
+            assert_eq!(AirdropTree::new(&allocations).unwrap().num_leaves(), count as u32);
+            assert_all_verify(&allocations);
+        }
+    }
+
+    #[test]
+    fn carried_node_has_a_shorter_proof() {
+        let tree = AirdropTree::new(&allocations(5)).unwrap();
+        assert_eq!(tree.proof(0).len(), 3);
+        assert_eq!(tree.proof(4).len(), 1);
+    }
+
+    #[test]
+    fn tampered_claims_do_not_verify() {
+        let allocations = allocations(5);
+        let tree = AirdropTree::new(&allocations).unwrap();
+        let (asset, amount) = allocations[2];
+        let proof = tree.proof(2);
+        let root = tree.root();
+
+        assert!(verify(&proof, &root, leaf(2, &asset, amount)));
+        assert!(!verify(&proof, &root, leaf(2, &asset, amount + 1)));
+        assert!(!verify(&proof, &root, leaf(3, &asset, amount)));
+        assert!(!verify(&proof, &root, leaf(2, &allocations[3].0, amount)));
+        assert!(!verify(&tree.proof(3), &root, leaf(2, &asset, amount)));
+    }
+}
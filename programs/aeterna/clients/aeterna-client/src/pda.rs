@@ -0,0 +1,220 @@
+//! PDA derivation helpers. Seeds must stay in sync with the `#[account(seeds = ...)]`
+//! constraints in `programs/aeterna/src/instructions`.
+
+use solana_program::pubkey::Pubkey;
+
+/// ["soul_stats", asset]
+pub fn soul_stats(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"soul_stats", asset.as_ref()], &aeterna::ID)
+}
+
+/// ["pulse", asset]
+pub fn pulse_link(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pulse", asset.as_ref()], &aeterna::ID)
+}
+
+/// ["pulse_vault", asset, owner] — token authority of `owner`'s Pulse vaults for `asset`
+pub fn pulse_vault_authority(asset: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pulse_vault", asset.as_ref(), owner.as_ref()], &aeterna::ID)
+}
+
+/// Associated token account of `owner`'s vault authority for `mint` — the Pulse spending vault
+pub fn pulse_vault(asset: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(&pulse_vault_authority(asset, owner).0, mint)
+}
+
+/// ["event", name]
+pub fn event(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"event", name.as_bytes()], &aeterna::ID)
+}
+
+/// ["quest", event, name]
+pub fn quest(event: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"quest", event.as_ref(), name.as_bytes()], &aeterna::ID)
+}
+
+/// ["completion", quest, asset]
+pub fn completion(quest: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"completion", quest.as_ref(), asset.as_ref()], &aeterna::ID)
+}
+
+/// ["leaderboard", event]
+pub fn leaderboard(event: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"leaderboard", event.as_ref()], &aeterna::ID)
+}
+
+/// ["participation", event, asset]
+pub fn participation(event: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"participation", event.as_ref(), asset.as_ref()], &aeterna::ID)
+}
+
+/// ["config"]
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &aeterna::ID)
+}
+
+/// ["achievement", name]
+pub fn achievement(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"achievement", name.as_bytes()], &aeterna::ID)
+}
+
+/// ["achievement_claim", achievement, asset]
+pub fn achievement_claim(achievement: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"achievement_claim", achievement.as_ref(), asset.as_ref()],
+        &aeterna::ID,
+    )
+}
+
+/// ["badge_authority"] — update authority of the badge collection
+pub fn badge_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"badge_authority"], &aeterna::ID)
+}
+
+/// ["attendance", event, asset] — exists iff the soul was checked in at the event
+pub fn attendance(event: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"attendance", event.as_ref(), asset.as_ref()], &aeterna::ID)
+}
+
+/// ["attendance_authority"] — update authority of every attendance collection
+pub fn attendance_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"attendance_authority"], &aeterna::ID)
+}
+
+/// ["reward", event, name]
+pub fn reward(event: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"reward", event.as_ref(), name.as_bytes()], &aeterna::ID)
+}
+
+/// ["redemption", reward, serial (u32 LE)]
+pub fn redemption(reward: &Pubkey, serial: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"redemption", reward.as_ref(), &serial.to_le_bytes()], &aeterna::ID)
+}
+
+/// ["airdrop", name]
+pub fn airdrop(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"airdrop", name.as_bytes()], &aeterna::ID)
+}
+
+/// ["airdrop_vault", airdrop] — token account holding the airdrop supply
+pub fn airdrop_vault(airdrop: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"airdrop_vault", airdrop.as_ref()], &aeterna::ID)
+}
+
+/// ["merchant", event, name]
+pub fn merchant(event: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"merchant", event.as_ref(), name.as_bytes()], &aeterna::ID)
+}
+
+/// ["treasury", mint] — protocol fee vault
+pub fn treasury_vault(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury", mint.as_ref()], &aeterna::ID)
+}
+
+/// ["boost", event, name]
+pub fn boost_window(event: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"boost", event.as_ref(), name.as_bytes()], &aeterna::ID)
+}
+
+/// ["grant_receipt", receipt_id] — `grant_xp` idempotency receipt
+pub fn grant_receipt(receipt_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"grant_receipt", receipt_id.as_ref()], &aeterna::ID)
+}
+
+/// ["action_type", name]
+pub fn action_type(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"action_type", name.as_bytes()], &aeterna::ID)
+}
+
+/// ["action_claim", action, asset]
+pub fn action_claim(action: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"action_claim", action.as_ref(), asset.as_ref()], &aeterna::ID)
+}
+
+/// ["xp_table"] — action-type XP table used by `grant_xp` and `claim_action_xp`
+pub fn xp_table() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"xp_table"], &aeterna::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Re-derive `address` from the program's literal seeds plus the returned bump
+    fn assert_seeds(derived: (Pubkey, u8), seeds: &[&[u8]]) {
+        let (address, bump) = derived;
+        let bump = [bump];
+        let mut with_bump = seeds.to_vec();
+        with_bump.push(&bump);
+        assert_eq!(Pubkey::create_program_address(&with_bump, &aeterna::ID).unwrap(), address);
+    }
+
+    #[test]
+    fn seeds_match_program_constraints() {
+        let asset = Pubkey::new_unique();
+        let event_key = Pubkey::new_unique();
+        let quest_key = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let receipt_id = [7u8; 32];
+
+        assert_seeds(soul_stats(&asset), &[b"soul_stats", asset.as_ref()]);
+        assert_seeds(pulse_link(&asset), &[b"pulse", asset.as_ref()]);
+        assert_seeds(
+            pulse_vault_authority(&asset, &quest_key),
+            &[b"pulse_vault", asset.as_ref(), quest_key.as_ref()],
+        );
+        assert_seeds(event("Techno Blast"), &[b"event", b"Techno Blast"]);
+        assert_seeds(quest(&event_key, "Main Stage"), &[b"quest", event_key.as_ref(), b"Main Stage"]);
+        assert_seeds(completion(&quest_key, &asset), &[b"completion", quest_key.as_ref(), asset.as_ref()]);
+        assert_seeds(leaderboard(&event_key), &[b"leaderboard", event_key.as_ref()]);
+        assert_seeds(
+            participation(&event_key, &asset),
+            &[b"participation", event_key.as_ref(), asset.as_ref()],
+        );
+        assert_seeds(config(), &[b"config"]);
+        assert_seeds(attendance(&event_key, &asset), &[b"attendance", event_key.as_ref(), asset.as_ref()]);
+        assert_seeds(reward(&event_key, "Drink"), &[b"reward", event_key.as_ref(), b"Drink"]);
+        assert_seeds(merchant(&event_key, "Bar"), &[b"merchant", event_key.as_ref(), b"Bar"]);
+        assert_seeds(treasury_vault(&mint), &[b"treasury", mint.as_ref()]);
+        assert_seeds(boost_window(&event_key, "Happy Hour"), &[b"boost", event_key.as_ref(), b"Happy Hour"]);
+        assert_seeds(grant_receipt(&receipt_id), &[b"grant_receipt", &receipt_id]);
+        assert_seeds(action_claim(&quest_key, &asset), &[b"action_claim", quest_key.as_ref(), asset.as_ref()]);
+        assert_seeds(xp_table(), &[b"xp_table"]);
+    }
+
+    #[test]
+    fn redemption_serial_is_little_endian() {
+        let reward_key = Pubkey::new_unique();
+        assert_seeds(redemption(&reward_key, 1), &[b"redemption", reward_key.as_ref(), &[1, 0, 0, 0]]);
+        assert_ne!(redemption(&reward_key, 1).0, redemption(&reward_key, 1 << 24).0);
+    }
+
+    #[test]
+    fn pulse_vault_is_per_owner() {
+        let asset = Pubkey::new_unique();
+        let seller = Pubkey::new_unique();
+        let buyer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        assert_eq!(
+            pulse_vault(&asset, &seller, &mint),
+            anchor_spl::associated_token::get_associated_token_address(
+                &pulse_vault_authority(&asset, &seller).0,
+                &mint
+            )
+        );
+        assert_ne!(pulse_vault(&asset, &seller, &mint), pulse_vault(&asset, &buyer, &mint));
+    }
+
+    #[test]
+    fn name_seeded_accounts_do_not_collide() {
+        let addresses = [
+            event("genesis").0,
+            achievement("genesis").0,
+            airdrop("genesis").0,
+            action_type("genesis").0,
+        ];
+        for (i, a) in addresses.iter().enumerate() {
+            assert!(addresses[i + 1..].iter().all(|b| a != b));
+        }
+    }
+}
@@ -0,0 +1,658 @@
+//! SQLite schema, event application and read queries.
+//!
+//! Events carry absolute after-values, so applying them is idempotent; history rows
+//! are keyed by (signature, seq) where `seq` is the event's position in the transaction.
+//! `u64` values are bound and read through rusqlite's checked conversions, so a transaction
+//! holding anything above `i64::MAX` is skipped whole instead of being stored wrapped.
+
+use std::path::Path;
+
+use aeterna_client::events::ProgramEvent;
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS passes (
+    asset            TEXT PRIMARY KEY,
+    owner            TEXT,
+    event            TEXT,
+    uri              TEXT,
+    pulse_wallet     TEXT,
+    minted_at        INTEGER,
+    stage            INTEGER NOT NULL DEFAULT 0,
+    level            INTEGER NOT NULL DEFAULT 0,
+    wealth_tier      INTEGER NOT NULL DEFAULT 0,
+    xp               INTEGER NOT NULL DEFAULT 0,
+    trading_volume   INTEGER NOT NULL DEFAULT 0,
+    quests_completed INTEGER NOT NULL DEFAULT 0
+);
+CREATE TABLE IF NOT EXISTS events (
+    event      TEXT PRIMARY KEY,
+    name       TEXT NOT NULL,
+    authority  TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS quests (
+    quest      TEXT PRIMARY KEY,
+    event      TEXT NOT NULL,
+    name       TEXT NOT NULL,
+    xp_reward  INTEGER NOT NULL,
+    created_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS xp_history (
+    signature TEXT NOT NULL,
+    seq       INTEGER NOT NULL,
+    asset     TEXT NOT NULL,
+    source    TEXT NOT NULL,
+    amount    INTEGER NOT NULL,
+    xp_before INTEGER NOT NULL,
+    xp_after  INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE TABLE IF NOT EXISTS quest_completions (
+    signature TEXT NOT NULL,
+    seq       INTEGER NOT NULL,
+    asset     TEXT NOT NULL,
+    quest     TEXT NOT NULL,
+    event     TEXT NOT NULL,
+    xp_reward INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE INDEX IF NOT EXISTS quest_completions_event ON quest_completions (event);
+CREATE TABLE IF NOT EXISTS evolutions (
+    signature  TEXT NOT NULL,
+    seq        INTEGER NOT NULL,
+    asset      TEXT NOT NULL,
+    from_stage INTEGER NOT NULL,
+    to_stage   INTEGER NOT NULL,
+    xp         INTEGER NOT NULL,
+    timestamp  INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE TABLE IF NOT EXISTS achievement_claims (
+    achievement TEXT NOT NULL,
+    asset       TEXT NOT NULL,
+    owner       TEXT NOT NULL,
+    badge       TEXT NOT NULL,
+    timestamp   INTEGER NOT NULL,
+    PRIMARY KEY (achievement, asset)
+);
+CREATE TABLE IF NOT EXISTS attendance (
+    event     TEXT NOT NULL,
+    asset     TEXT NOT NULL,
+    owner     TEXT NOT NULL,
+    proof     TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (event, asset)
+);
+CREATE TABLE IF NOT EXISTS redemptions (
+    redemption   TEXT PRIMARY KEY,
+    reward       TEXT NOT NULL,
+    asset        TEXT NOT NULL,
+    owner        TEXT NOT NULL,
+    serial       INTEGER NOT NULL,
+    cost         INTEGER NOT NULL,
+    redeemed_at  INTEGER NOT NULL,
+    fulfilled_at INTEGER
+);
+CREATE TABLE IF NOT EXISTS airdrop_claims (
+    airdrop   TEXT NOT NULL,
+    leaf      INTEGER NOT NULL,
+    asset     TEXT NOT NULL,
+    owner     TEXT NOT NULL,
+    amount    INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (airdrop, leaf)
+);
+CREATE TABLE IF NOT EXISTS presence (
+    event          TEXT NOT NULL,
+    asset          TEXT NOT NULL,
+    inside         INTEGER NOT NULL,
+    entries        INTEGER NOT NULL DEFAULT 0,
+    last_check_in  INTEGER,
+    last_check_out INTEGER,
+    PRIMARY KEY (event, asset)
+);
+CREATE TABLE IF NOT EXISTS grants (
+    signature  TEXT NOT NULL,
+    seq        INTEGER NOT NULL,
+    asset      TEXT NOT NULL,
+    receipt_id BLOB NOT NULL,
+    action     INTEGER NOT NULL,
+    count      INTEGER NOT NULL,
+    xp_amount  INTEGER NOT NULL,
+    timestamp  INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE TABLE IF NOT EXISTS boosts (
+    signature      TEXT NOT NULL,
+    seq            INTEGER NOT NULL,
+    boost          TEXT NOT NULL,
+    asset          TEXT NOT NULL,
+    base_xp        INTEGER NOT NULL,
+    boosted_xp     INTEGER NOT NULL,
+    multiplier_bps INTEGER NOT NULL,
+    timestamp      INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE TABLE IF NOT EXISTS cursor (
+    id        INTEGER PRIMARY KEY CHECK (id = 0),
+    signature TEXT NOT NULL,
+    slot      INTEGER NOT NULL
+);
+";
+
+pub struct Db {
+    conn: Connection,
+}
+
+pub struct LeaderboardRow {
+    pub asset: String,
+    pub xp: u64,
+    pub quests: u64,
+}
+
+pub struct QuestStats {
+    pub name: String,
+    pub completions: u64,
+}
+
+pub struct EventStats {
+    pub passes_minted: u64,
+    pub attended: u64,
+    pub inside: u64,
+    pub participants: u64,
+    pub completions: u64,
+    pub xp_awarded: u64,
+    pub quests: Vec<QuestStats>,
+}
+
+impl Db {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Last signature ingested from RPC
+    pub fn cursor(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row("SELECT signature FROM cursor WHERE id = 0", [], |row| row.get(0))
+            .optional()?)
+    }
+
+    /// Apply every event of one transaction atomically and advance the cursor.
+    ///
+    /// Returns `false` when an event holds a value SQLite cannot store: none of the
+    /// transaction's events are applied, but the cursor still moves past it so a
+    /// single oversized value cannot stall ingestion.
+    pub fn apply_transaction(
+        &mut self,
+        signature: &str,
+        slot: u64,
+        events: &[ProgramEvent],
+    ) -> Result<bool> {
+        let tx = self.conn.transaction()?;
+        for (seq, event) in events.iter().enumerate() {
+            if let Err(err) = apply(&tx, signature, seq as i64, event) {
+                if !is_unstorable(&err) {
+                    return Err(err);
+                }
+                tx.rollback()?;
+                set_cursor(&self.conn, signature, slot)?;
+                return Ok(false);
+            }
+        }
+        set_cursor(&tx, signature, slot)?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Top souls by lifetime XP
+    pub fn leaderboard(&self, limit: u32) -> Result<Vec<LeaderboardRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT asset, xp, quests_completed FROM passes ORDER BY xp DESC, asset LIMIT ?1",
+        )?;
+        let rows = stmt.query_map([limit], |row| {
+            Ok(LeaderboardRow {
+                asset: row.get(0)?,
+                xp: row.get::<_, u64>(1)?,
+                quests: row.get::<_, u64>(2)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Top souls by quest XP earned at a single event
+    pub fn event_leaderboard(&self, event: &str, limit: u32) -> Result<Vec<LeaderboardRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT asset, SUM(xp_reward), COUNT(*) FROM quest_completions
+             WHERE event = ?1 GROUP BY asset ORDER BY 2 DESC, asset LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![event, limit], |row| {
+            Ok(LeaderboardRow {
+                asset: row.get(0)?,
+                xp: row.get::<_, u64>(1)?,
+                quests: row.get::<_, u64>(2)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn event_stats(&self, event: &str) -> Result<EventStats> {
+        let passes_minted: u64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM passes WHERE event = ?1",
+            [event],
+            |row| row.get(0),
+        )?;
+        let attended: u64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM attendance WHERE event = ?1",
+            [event],
+            |row| row.get(0),
+        )?;
+        let inside: u64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM presence WHERE event = ?1 AND inside = 1",
+            [event],
+            |row| row.get(0),
+        )?;
+        let (participants, completions, xp_awarded): (u64, u64, u64) = self.conn.query_row(
+            "SELECT COUNT(DISTINCT asset), COUNT(*), COALESCE(SUM(xp_reward), 0)
+             FROM quest_completions WHERE event = ?1",
+            [event],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT q.name, COUNT(c.quest) FROM quests q
+             LEFT JOIN quest_completions c ON c.quest = q.quest
+             WHERE q.event = ?1 GROUP BY q.quest ORDER BY 2 DESC, q.name",
+        )?;
+        let quests = stmt
+            .query_map([event], |row| {
+                Ok(QuestStats {
+                    name: row.get(0)?,
+                    completions: row.get::<_, u64>(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(EventStats {
+            passes_minted,
+            attended,
+            inside,
+            participants,
+            completions,
+            xp_awarded,
+            quests,
+        })
+    }
+}
+
+fn set_cursor(conn: &Connection, signature: &str, slot: u64) -> Result<()> {
+    conn.execute(
+        "INSERT INTO cursor (id, signature, slot) VALUES (0, ?1, ?2)
+         ON CONFLICT(id) DO UPDATE SET signature = excluded.signature, slot = excluded.slot",
+        params![signature, slot],
+    )?;
+    Ok(())
+}
+
+/// A `u64` above `i64::MAX` failed rusqlite's checked conversion
+fn is_unstorable(err: &anyhow::Error) -> bool {
+    matches!(err.downcast_ref::<rusqlite::Error>(), Some(rusqlite::Error::ToSqlConversionFailure(_)))
+}
+
+fn apply(conn: &Connection, signature: &str, seq: i64, event: &ProgramEvent) -> Result<()> {
+    match event {
+        ProgramEvent::PassMinted(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, owner, event, uri, minted_at, level) VALUES (?1, ?2, ?3, ?4, ?5, 1)
+                 ON CONFLICT(asset) DO UPDATE SET owner = excluded.owner, event = excluded.event,
+                     uri = excluded.uri, minted_at = excluded.minted_at",
+                params![e.asset.to_string(), e.owner.to_string(), e.event.to_string(), e.uri, e.timestamp],
+            )?;
+        }
+        ProgramEvent::PulseLinked(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, pulse_wallet) VALUES (?1, ?2)
+                 ON CONFLICT(asset) DO UPDATE SET pulse_wallet = excluded.pulse_wallet",
+                params![e.asset.to_string(), e.pulse_wallet.to_string()],
+            )?;
+        }
+        ProgramEvent::PulseUnlinked(e) => {
+            conn.execute(
+                "UPDATE passes SET pulse_wallet = NULL WHERE asset = ?1",
+                params![e.asset.to_string()],
+            )?;
+        }
+        ProgramEvent::XpGranted(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, xp, trading_volume, quests_completed) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(asset) DO UPDATE SET xp = excluded.xp,
+                     trading_volume = excluded.trading_volume, quests_completed = excluded.quests_completed",
+                params![
+                    e.asset.to_string(),
+                    e.xp_after,
+                    e.trading_volume_after,
+                    e.quests_completed_after
+                ],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO xp_history VALUES (?1, ?2, ?3, 'grant', ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.xp_amount,
+                    e.xp_before,
+                    e.xp_after,
+                    e.timestamp
+                ],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO grants VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    &e.receipt_id[..],
+                    e.action,
+                    e.count,
+                    e.xp_amount,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::PulsePayment(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, xp, trading_volume) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(asset) DO UPDATE SET xp = excluded.xp, trading_volume = excluded.trading_volume",
+                params![e.asset.to_string(), e.xp_after, e.trading_volume_after],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO xp_history VALUES (?1, ?2, ?3, 'pulse', ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.xp_awarded,
+                    e.xp_before,
+                    e.xp_after,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::QuestCompleted(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, xp, quests_completed) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(asset) DO UPDATE SET xp = excluded.xp,
+                     quests_completed = excluded.quests_completed",
+                params![e.asset.to_string(), e.xp_after, e.quests_completed_after],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO quest_completions VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.quest.to_string(),
+                    e.event.to_string(),
+                    e.xp_reward,
+                    e.timestamp
+                ],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO xp_history VALUES (?1, ?2, ?3, 'quest', ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.xp_reward,
+                    e.xp_before,
+                    e.xp_after,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::SoulEvolved(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, stage) VALUES (?1, ?2)
+                 ON CONFLICT(asset) DO UPDATE SET stage = excluded.stage",
+                params![e.asset.to_string(), e.new_stage],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO evolutions VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.previous_stage,
+                    e.new_stage,
+                    e.xp,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::EventCreated(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO events VALUES (?1, ?2, ?3, ?4)",
+                params![e.event.to_string(), e.name, e.authority.to_string(), e.timestamp],
+            )?;
+        }
+        ProgramEvent::QuestCreated(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO quests VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    e.quest.to_string(),
+                    e.event.to_string(),
+                    e.name,
+                    e.xp_reward,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::LevelUp(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, level) VALUES (?1, ?2)
+                 ON CONFLICT(asset) DO UPDATE SET level = excluded.level",
+                params![e.asset.to_string(), e.new_level],
+            )?;
+        }
+        ProgramEvent::WealthTierChanged(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, wealth_tier, trading_volume) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(asset) DO UPDATE SET wealth_tier = excluded.wealth_tier,
+                     trading_volume = excluded.trading_volume",
+                params![e.asset.to_string(), e.new_tier, e.trading_volume],
+            )?;
+        }
+        ProgramEvent::AchievementClaimed(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO achievement_claims VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    e.achievement.to_string(),
+                    e.asset.to_string(),
+                    e.owner.to_string(),
+                    e.badge.to_string(),
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::AttendanceRecorded(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO attendance VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    e.event.to_string(),
+                    e.asset.to_string(),
+                    e.owner.to_string(),
+                    e.proof.to_string(),
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::RewardRedeemed(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO redemptions (redemption, reward, asset, owner, serial, cost, redeemed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    e.redemption.to_string(),
+                    e.reward.to_string(),
+                    e.asset.to_string(),
+                    e.owner.to_string(),
+                    e.serial,
+                    e.cost,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::RedemptionFulfilled(e) => {
+            conn.execute(
+                "UPDATE redemptions SET fulfilled_at = ?2 WHERE redemption = ?1",
+                params![e.redemption.to_string(), e.timestamp],
+            )?;
+        }
+        ProgramEvent::AirdropCreated(_) | ProgramEvent::AirdropSwept(_) => {}
+        ProgramEvent::AirdropClaimed(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO airdrop_claims VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    e.airdrop.to_string(),
+                    e.index,
+                    e.asset.to_string(),
+                    e.owner.to_string(),
+                    e.amount,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::CheckedIn(e) => {
+            conn.execute(
+                "INSERT INTO presence (event, asset, inside, entries, last_check_in) VALUES (?1, ?2, 1, ?3, ?4)
+                 ON CONFLICT(event, asset) DO UPDATE SET inside = 1, entries = excluded.entries,
+                     last_check_in = excluded.last_check_in",
+                params![e.event.to_string(), e.asset.to_string(), e.entries, e.timestamp],
+            )?;
+        }
+        ProgramEvent::CheckedOut(e) => {
+            conn.execute(
+                "INSERT INTO presence (event, asset, inside, last_check_out) VALUES (?1, ?2, 0, ?3)
+                 ON CONFLICT(event, asset) DO UPDATE SET inside = 0, last_check_out = excluded.last_check_out",
+                params![e.event.to_string(), e.asset.to_string(), e.timestamp],
+            )?;
+        }
+        ProgramEvent::MerchantUpdated(_) => {}
+        ProgramEvent::ActionXpClaimed(e) => {
+            conn.execute(
+                "INSERT INTO passes (asset, xp) VALUES (?1, ?2)
+                 ON CONFLICT(asset) DO UPDATE SET xp = excluded.xp",
+                params![e.asset.to_string(), e.xp_after],
+            )?;
+            conn.execute(
+                "INSERT OR IGNORE INTO xp_history VALUES (?1, ?2, ?3, 'action', ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    e.asset.to_string(),
+                    e.xp_reward,
+                    e.xp_before,
+                    e.xp_after,
+                    e.timestamp
+                ],
+            )?;
+        }
+        ProgramEvent::XpBoosted(e) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO boosts VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    signature,
+                    seq,
+                    e.boost.to_string(),
+                    e.asset.to_string(),
+                    e.base_xp,
+                    e.boosted_xp,
+                    e.multiplier_bps,
+                    e.timestamp
+                ],
+            )?;
+        }
+        // Admin and configuration changes carry no per-pass state
+        ProgramEvent::LevelTableUpdated(_)
+        | ProgramEvent::WealthTiersUpdated(_)
+        | ProgramEvent::XpCapsUpdated(_)
+        | ProgramEvent::XpTableUpdated(_)
+        | ProgramEvent::AccountMigrated(_)
+        | ProgramEvent::LeaderboardCreated(_)
+        | ProgramEvent::BadgeCollectionInitialized(_)
+        | ProgramEvent::AchievementCreated(_)
+        | ProgramEvent::AttendanceEnabled(_)
+        | ProgramEvent::ReentryPolicyUpdated(_)
+        | ProgramEvent::RewardCreated(_)
+        | ProgramEvent::RewardUpdated(_)
+        | ProgramEvent::PulseLimitsUpdated(_)
+        | ProgramEvent::PulseSpent(_)
+        | ProgramEvent::PulseWithdrawn(_)
+        | ProgramEvent::TreasuryVaultInitialized(_)
+        | ProgramEvent::TreasuryWithdrawn(_)
+        | ProgramEvent::BoostWindowCreated(_)
+        | ProgramEvent::BoostWindowClosed(_)
+        | ProgramEvent::GrantReceiptClosed(_)
+        | ProgramEvent::ActionTypeUpdated(_)
+        | ProgramEvent::PaymentConfigUpdated(_) => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aeterna_client::events::XpGranted;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn xp_granted(asset: Pubkey, xp_after: u64) -> ProgramEvent {
+        ProgramEvent::XpGranted(XpGranted {
+            asset,
+            receipt_id: [1; 32],
+            action: 0,
+            count: 1,
+            xp_amount: 100,
+            xp_before: xp_after.saturating_sub(100),
+            xp_after,
+            trading_volume_before: 0,
+            trading_volume_after: 0,
+            quests_completed_before: 0,
+            quests_completed_after: 0,
+            timestamp: 1_700_000_000,
+        })
+    }
+
+    #[test]
+    fn stores_xp_without_wrapping() {
+        let mut db = Db::open(Path::new(":memory:")).unwrap();
+        let asset = Pubkey::new_unique();
+        assert!(db.apply_transaction("sig", 7, &[xp_granted(asset, i64::MAX as u64)]).unwrap());
+
+        let rows = db.leaderboard(10).unwrap();
+        assert_eq!(rows[0].asset, asset.to_string());
+        assert_eq!(rows[0].xp, i64::MAX as u64);
+    }
+
+    #[test]
+    fn skips_values_sqlite_cannot_hold() {
+        let mut db = Db::open(Path::new(":memory:")).unwrap();
+        let events = [xp_granted(Pubkey::new_unique(), 50), xp_granted(Pubkey::new_unique(), u64::MAX)];
+        let applied = db.apply_transaction("sig", 7, &events).unwrap();
+        assert!(!applied);
+        // The events are rolled back but the cursor moves past the transaction
+        assert_eq!(db.cursor().unwrap().as_deref(), Some("sig"));
+        assert!(db.leaderboard(10).unwrap().is_empty());
+
+        // Later transactions still index
+        let asset = Pubkey::new_unique();
+        assert!(db.apply_transaction("sig2", 8, &[xp_granted(asset, 200)]).unwrap());
+        assert_eq!(db.cursor().unwrap().as_deref(), Some("sig2"));
+        assert_eq!(db.leaderboard(10).unwrap()[0].asset, asset.to_string());
+    }
+}
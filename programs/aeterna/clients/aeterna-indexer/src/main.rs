@@ -0,0 +1,111 @@
+//! `aeterna-indexer` — builds a local SQLite view of passes, XP history, quest
+//! completions and evolutions from the program's emitted events.
+
+mod db;
+mod source;
+
+use std::{path::PathBuf, thread, time::Duration};
+
+use aeterna_client::{events, pda};
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+use db::Db;
+use source::RecordedTx;
+
+#[derive(Parser)]
+#[command(name = "aeterna-indexer", about = "Index AETERNA events into SQLite")]
+struct Cli {
+    /// SQLite database path
+    #[arg(long, env = "AETERNA_INDEX_DB", default_value = "aeterna-index.sqlite")]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Ingest a JSON-lines file of recorded transactions (for local testing)
+    IngestFile { path: PathBuf },
+    /// Ingest new program transactions from an RPC endpoint
+    IngestRpc {
+        #[arg(long, env = "AETERNA_RPC_URL", default_value = "http://127.0.0.1:8899")]
+        url: String,
+        /// Keep polling at this interval (seconds) instead of exiting after one pass
+        #[arg(long)]
+        poll: Option<u64>,
+    },
+    /// Top souls by lifetime XP, or by quest XP at one event
+    Leaderboard {
+        #[arg(long)]
+        event: Option<String>,
+        #[arg(long, default_value_t = 50)]
+        limit: u32,
+    },
+    /// Participation summary for an event
+    EventStats { event: String },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut db = Db::open(&cli.db)?;
+
+    match cli.command {
+        Command::IngestFile { path } => {
+            let count = ingest(&mut db, source::read_file(&path)?)?;
+            println!("ingested {count} events from {}", path.display());
+        }
+        Command::IngestRpc { url, poll } => {
+            let rpc = RpcClient::new_with_commitment(url, CommitmentConfig::confirmed());
+            loop {
+                let cursor = db.cursor()?;
+                let count = ingest(&mut db, source::fetch_rpc(&rpc, cursor.as_deref())?)?;
+                println!("ingested {count} events");
+                match poll {
+                    Some(secs) => thread::sleep(Duration::from_secs(secs)),
+                    None => break,
+                }
+            }
+        }
+        Command::Leaderboard { event, limit } => {
+            let rows = match &event {
+                Some(name) => db.event_leaderboard(&pda::event(name).0.to_string(), limit)?,
+                None => db.leaderboard(limit)?,
+            };
+            for (rank, row) in rows.iter().enumerate() {
+                println!("{:>3}. {}  {:>10} XP  {:>4} quests", rank + 1, row.asset, row.xp, row.quests);
+            }
+        }
+        Command::EventStats { event } => {
+            let stats = db.event_stats(&pda::event(&event).0.to_string())?;
+            println!("Event         {event}");
+            println!("passes minted {}", stats.passes_minted);
+            println!("attended      {}", stats.attended);
+            println!("inside now    {}", stats.inside);
+            println!("participants  {}", stats.participants);
+            println!("completions   {}", stats.completions);
+            println!("xp awarded    {}", stats.xp_awarded);
+            for quest in stats.quests {
+                println!("  {:<32} {}", quest.name, quest.completions);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Apply transactions in order; returns the number of events indexed
+fn ingest(db: &mut Db, transactions: Vec<RecordedTx>) -> Result<usize> {
+    let mut count = 0;
+    for tx in transactions {
+        let decoded = events::decode_logs(&tx.logs);
+        if db.apply_transaction(&tx.signature, tx.slot, &decoded)? {
+            count += decoded.len();
+        } else {
+            eprintln!("skipped {}: holds a value above i64::MAX", tx.signature);
+        }
+    }
+    Ok(count)
+}
@@ -0,0 +1,89 @@
+//! Transaction sources: a JSON-lines file of recorded transactions, or live RPC.
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+
+/// One transaction's logs, as recorded by the file format or fetched over RPC.
+///
+/// File format: one JSON object per line:
+/// `{"signature": "...", "slot": 1, "logs": ["Program data: ..."]}`
+#[derive(Debug, Deserialize)]
+pub struct RecordedTx {
+    pub signature: String,
+    pub slot: u64,
+    pub logs: Vec<String>,
+}
+
+pub fn read_file(path: &Path) -> Result<Vec<RecordedTx>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .map(|(n, line)| {
+            serde_json::from_str(&line?)
+                .with_context(|| format!("{}:{}: invalid recorded transaction", path.display(), n + 1))
+        })
+        .collect()
+}
+
+/// Fetch every program transaction newer than `until` (exclusive), oldest first.
+pub fn fetch_rpc(rpc: &RpcClient, until: Option<&str>) -> Result<Vec<RecordedTx>> {
+    let until = until.map(Signature::from_str).transpose()?;
+
+    // getSignaturesForAddress pages newest → oldest; walk back until `until`
+    let mut signatures = Vec::new();
+    let mut before = None;
+    loop {
+        let page = rpc.get_signatures_for_address_with_config(
+            &aeterna::ID,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )?;
+        let Some(last) = page.last() else { break };
+        before = Some(Signature::from_str(&last.signature)?);
+        signatures.extend(page.into_iter().filter(|status| status.err.is_none()));
+    }
+    signatures.reverse();
+
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Json),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+    signatures
+        .into_iter()
+        .map(|status| {
+            let tx = rpc
+                .get_transaction_with_config(&Signature::from_str(&status.signature)?, config)
+                .with_context(|| format!("fetching transaction {}", status.signature))?;
+            let logs = tx
+                .transaction
+                .meta
+                .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+                .unwrap_or_default();
+            Ok(RecordedTx {
+                signature: status.signature,
+                slot: tx.slot,
+                logs,
+            })
+        })
+        .collect()
+}
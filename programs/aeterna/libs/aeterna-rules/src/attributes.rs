@@ -0,0 +1,169 @@
+//! Protocol attribute schema and construction for AETERNA Pass Core assets.
+
+use alloc::{string::String, string::ToString, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{stage, wealth};
+
+/// Keys written exclusively by the program from on-chain state.
+/// This order is also the canonical display order.
+pub const RESERVED_KEYS: &[&str] = &[
+    "status",
+    "stage",
+    "level",
+    "title",
+    "wealth_tier",
+    "xp",
+    "quests_completed",
+    "last_quest",
+];
+
+/// Cosmetic keys that the backend may set when evolving a Soul
+pub const ALLOWED_CUSTOM_KEYS: &[&str] = &[
+    "aura",
+    "background",
+    "element",
+    "form",
+    "last_action",
+];
+
+/// Max number of caller-supplied attributes per update
+pub const MAX_CUSTOM_ATTRIBUTES: usize = 5;
+/// Max byte length of an attribute key
+pub const MAX_KEY_LEN: usize = 32;
+/// Max byte length of an attribute value
+pub const MAX_VALUE_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeError {
+    TooManyAttributes,
+    KeyTooLong,
+    ValueTooLong,
+    ReservedKey,
+    UnknownKey,
+    DuplicateKey,
+}
+
+/// Validate caller-supplied `(key, value)` pairs against the schema
+pub fn validate_custom(attributes: &[(&str, &str)]) -> Result<(), AttributeError> {
+    if attributes.len() > MAX_CUSTOM_ATTRIBUTES {
+        return Err(AttributeError::TooManyAttributes);
+    }
+
+    for (i, (key, value)) in attributes.iter().enumerate() {
+        if key.len() > MAX_KEY_LEN {
+            return Err(AttributeError::KeyTooLong);
+        }
+        if value.len() > MAX_VALUE_LEN {
+            return Err(AttributeError::ValueTooLong);
+        }
+        if RESERVED_KEYS.contains(key) {
+            return Err(AttributeError::ReservedKey);
+        }
+        if !ALLOWED_CUSTOM_KEYS.contains(key) {
+            return Err(AttributeError::UnknownKey);
+        }
+        if attributes[..i].iter().any(|(k, _)| k == key) {
+            return Err(AttributeError::DuplicateKey);
+        }
+    }
+
+    Ok(())
+}
+
+/// Position of `key` in canonical order; custom keys sort after every reserved key
+pub fn canonical_rank(key: &str) -> usize {
+    RESERVED_KEYS
+        .iter()
+        .position(|k| *k == key)
+        .unwrap_or(RESERVED_KEYS.len())
+}
+
+/// Canonical ordering: reserved keys in `RESERVED_KEYS` order, then custom keys alphabetically
+pub fn canonical_cmp(a: &str, b: &str) -> Ordering {
+    canonical_rank(a).cmp(&canonical_rank(b)).then_with(|| a.cmp(b))
+}
+
+/// The on-chain state a pass's reserved attributes are derived from
+pub struct SoulSnapshot<'a> {
+    pub stage: u8,
+    pub level: u8,
+    pub title: &'a str,
+    pub wealth_tier: u8,
+    pub xp: u64,
+    pub quests_completed: u32,
+}
+
+/// Reserved attributes derived from `soul`, in canonical order (`last_quest` is quest-scoped
+/// and left to the caller)
+pub fn soul_attributes(soul: &SoulSnapshot) -> Vec<(&'static str, String)> {
+    alloc::vec![
+        ("status", stage::status(soul.stage).to_string()),
+        ("stage", soul.stage.to_string()),
+        ("level", soul.level.to_string()),
+        ("title", soul.title.to_string()),
+        ("wealth_tier", wealth::label(soul.wealth_tier).to_string()),
+        ("xp", soul.xp.to_string()),
+        ("quests_completed", soul.quests_completed.to_string()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn accepts_allowed_custom_keys() {
+        assert_eq!(validate_custom(&[]), Ok(()));
+        assert_eq!(validate_custom(&[("aura", "violet"), ("element", "fire")]), Ok(()));
+    }
+
+    #[test]
+    fn rejects_invalid_attributes() {
+        let long_key = "k".repeat(MAX_KEY_LEN + 1);
+        let long_value = "v".repeat(MAX_VALUE_LEN + 1);
+        assert_eq!(validate_custom(&[("xp", "9999")]), Err(AttributeError::ReservedKey));
+        assert_eq!(validate_custom(&[("hat", "red")]), Err(AttributeError::UnknownKey));
+        assert_eq!(validate_custom(&[(&long_key, "x")]), Err(AttributeError::KeyTooLong));
+        assert_eq!(validate_custom(&[("aura", &long_value)]), Err(AttributeError::ValueTooLong));
+        assert_eq!(
+            validate_custom(&[("aura", "a"), ("form", "b"), ("aura", "c")]),
+            Err(AttributeError::DuplicateKey)
+        );
+        let too_many = vec![("aura", "x"); MAX_CUSTOM_ATTRIBUTES + 1];
+        assert_eq!(validate_custom(&too_many), Err(AttributeError::TooManyAttributes));
+    }
+
+    #[test]
+    fn every_reserved_key_is_rejected() {
+        for key in RESERVED_KEYS {
+            assert_eq!(validate_custom(&[(key, "x")]), Err(AttributeError::ReservedKey));
+        }
+    }
+
+    #[test]
+    fn canonical_order() {
+        let mut keys = vec!["element", "xp", "aura", "status", "last_quest"];
+        keys.sort_by(|a, b| canonical_cmp(a, b));
+        assert_eq!(keys, ["status", "xp", "last_quest", "aura", "element"]);
+    }
+
+    #[test]
+    fn soul_attributes_follow_reserved_order() {
+        let soul = SoulSnapshot {
+            stage: stage::ACTIVE,
+            level: 3,
+            title: "Awakened",
+            wealth_tier: wealth::GOLD,
+            xp: 620,
+            quests_completed: 4,
+        };
+        let attributes = soul_attributes(&soul);
+        let keys: alloc::vec::Vec<_> = attributes.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, RESERVED_KEYS[..RESERVED_KEYS.len() - 1]);
+        assert_eq!(attributes[0].1, "Active");
+        assert_eq!(attributes[4].1, "Gold");
+        assert_eq!(attributes[5].1, "620");
+    }
+}
@@ -0,0 +1,55 @@
+//! Level ladder lookup, mirroring the API's `XPEngine`.
+
+/// 1-indexed level for `xp`: the number of ascending thresholds reached (minimum 1)
+pub fn level_for<I: IntoIterator<Item = u64>>(thresholds: I, xp: u64) -> u8 {
+    let reached = thresholds.into_iter().take_while(|min_xp| xp >= *min_xp).count();
+    reached.clamp(1, u8::MAX as usize) as u8
+}
+
+/// A ladder is valid when non-empty, starts at 0 XP and strictly ascends
+pub fn is_valid_ladder(thresholds: &[u64]) -> bool {
+    thresholds.first() == Some(&0) && thresholds.windows(2).all(|w| w[0] < w[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LADDER: [u64; 6] = [0, 100, 500, 1500, 5000, 15000];
+
+    #[test]
+    fn level_boundaries() {
+        assert_eq!(level_for(LADDER, 0), 1);
+        assert_eq!(level_for(LADDER, 99), 1);
+        assert_eq!(level_for(LADDER, 100), 2);
+        assert_eq!(level_for(LADDER, 14_999), 5);
+        assert_eq!(level_for(LADDER, u64::MAX), 6);
+    }
+
+    #[test]
+    fn level_is_at_least_one_and_monotonic() {
+        assert_eq!(level_for([], 500), 1);
+        assert_eq!(level_for([10], 0), 1);
+        let mut previous = 1;
+        for xp in (0..20_000).step_by(7) {
+            let level = level_for(LADDER, xp);
+            assert!(level >= previous);
+            previous = level;
+        }
+    }
+
+    #[test]
+    fn level_saturates_at_u8_max() {
+        assert_eq!(level_for(0..1_000, u64::MAX), u8::MAX);
+    }
+
+    #[test]
+    fn ladder_validation() {
+        assert!(is_valid_ladder(&LADDER));
+        assert!(is_valid_ladder(&[0]));
+        assert!(!is_valid_ladder(&[]));
+        assert!(!is_valid_ladder(&[1, 100]));
+        assert!(!is_valid_ladder(&[0, 100, 100]));
+        assert!(!is_valid_ladder(&[0, 500, 100]));
+    }
+}
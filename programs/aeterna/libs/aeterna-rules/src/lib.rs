@@ -0,0 +1,15 @@
+//! Pure game rules for AETERNA souls.
+//!
+//! Everything here is deterministic and allocation-light so the on-chain program and
+//! off-chain Rust services evaluate exactly the same logic.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod attributes;
+pub mod level;
+pub mod stage;
+pub mod wealth;
+pub mod window;
+pub mod xp;
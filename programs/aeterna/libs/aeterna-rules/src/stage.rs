@@ -0,0 +1,107 @@
+//! Evolution stages and their eligibility rules.
+
+pub const DORMANT: u8 = 0;
+pub const ACTIVE: u8 = 1;
+pub const ASCENDED: u8 = 2;
+
+/// XP required to reach ACTIVE state (stage 1)
+pub const THRESHOLD_ACTIVE: u64 = 100;
+/// XP required to reach ASCENDED state (stage 2)
+pub const THRESHOLD_ASCENDED: u64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionError {
+    /// Target is not a real stage, or would not move the soul forward
+    InvalidStage,
+    /// XP is below the target stage's threshold
+    NotEnoughXp,
+    /// Ascension requires a higher wealth tier
+    WealthTierTooLow,
+}
+
+/// XP threshold for a stage, or `None` for stages that cannot be evolved into
+pub fn required_xp(stage: u8) -> Option<u64> {
+    match stage {
+        ACTIVE => Some(THRESHOLD_ACTIVE),
+        ASCENDED => Some(THRESHOLD_ASCENDED),
+        _ => None,
+    }
+}
+
+/// Whether a soul may evolve from `current` to `target`.
+/// Stages only move forward, and Ascension may also require `ascend_min_wealth_tier`.
+pub fn check_evolution(
+    current: u8,
+    target: u8,
+    xp: u64,
+    wealth_tier: u8,
+    ascend_min_wealth_tier: u8,
+) -> Result<(), EvolutionError> {
+    if target <= current {
+        return Err(EvolutionError::InvalidStage);
+    }
+    let required = required_xp(target).ok_or(EvolutionError::InvalidStage)?;
+    if xp < required {
+        return Err(EvolutionError::NotEnoughXp);
+    }
+    if target == ASCENDED && wealth_tier < ascend_min_wealth_tier {
+        return Err(EvolutionError::WealthTierTooLow);
+    }
+    Ok(())
+}
+
+/// Per mechanics_review.md: "Dormant: Cannot earn XP"
+pub fn can_earn_xp(stage: u8) -> bool {
+    stage >= ACTIVE
+}
+
+/// Human readable status for an evolution stage
+pub fn status(stage: u8) -> &'static str {
+    match stage {
+        ACTIVE => "Active",
+        ASCENDED => "Ascended",
+        _ => "Dormant",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evolution_thresholds() {
+        assert_eq!(check_evolution(DORMANT, ACTIVE, 99, 0, 0), Err(EvolutionError::NotEnoughXp));
+        assert_eq!(check_evolution(DORMANT, ACTIVE, 100, 0, 0), Ok(()));
+        assert_eq!(check_evolution(ACTIVE, ASCENDED, 999, 0, 0), Err(EvolutionError::NotEnoughXp));
+        assert_eq!(check_evolution(ACTIVE, ASCENDED, 1000, 0, 0), Ok(()));
+        // Skipping a stage is allowed as long as the target's threshold is met
+        assert_eq!(check_evolution(DORMANT, ASCENDED, 1000, 0, 0), Ok(()));
+    }
+
+    #[test]
+    fn stages_only_move_forward_to_real_stages() {
+        for current in 0..=u8::MAX {
+            for target in 0..=current {
+                assert_eq!(check_evolution(current, target, u64::MAX, 2, 0), Err(EvolutionError::InvalidStage));
+            }
+        }
+        assert_eq!(check_evolution(ASCENDED, 3, u64::MAX, 2, 0), Err(EvolutionError::InvalidStage));
+    }
+
+    #[test]
+    fn ascension_wealth_gate() {
+        assert_eq!(check_evolution(ACTIVE, ASCENDED, 1000, 0, 1), Err(EvolutionError::WealthTierTooLow));
+        assert_eq!(check_evolution(ACTIVE, ASCENDED, 1000, 1, 1), Ok(()));
+        // The wealth gate never applies to ACTIVE
+        assert_eq!(check_evolution(DORMANT, ACTIVE, 100, 0, 2), Ok(()));
+    }
+
+    #[test]
+    fn dormant_souls_cannot_earn() {
+        assert!(!can_earn_xp(DORMANT));
+        assert!(can_earn_xp(ACTIVE));
+        assert!(can_earn_xp(ASCENDED));
+        assert_eq!(status(DORMANT), "Dormant");
+        assert_eq!(status(7), "Dormant");
+    }
+}
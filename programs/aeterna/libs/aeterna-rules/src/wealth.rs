@@ -0,0 +1,104 @@
+//! Wealth tiers derived from cumulative trading volume.
+
+pub const COMMON: u8 = 0;
+pub const GOLD: u8 = 1;
+pub const DIAMOND: u8 = 2;
+
+/// Trading volume is kept in one unit whatever token it was spent in: 6-decimal
+/// stablecoin base units (1_000_000 = one whole token)
+pub const VOLUME_DECIMALS: u8 = 6;
+
+/// Convert `amount` base units of a token with `decimals` decimals to volume units,
+/// rounding down and saturating at `u64::MAX`
+pub fn normalize_volume(amount: u64, decimals: u8) -> u64 {
+    if decimals >= VOLUME_DECIMALS {
+        10u64
+            .checked_pow((decimals - VOLUME_DECIMALS) as u32)
+            .map_or(0, |scale| amount / scale)
+    } else {
+        amount.saturating_mul(10u64.pow((VOLUME_DECIMALS - decimals) as u32))
+    }
+}
+
+/// Tier for `volume`. A threshold of 0 disables that tier.
+pub fn tier_for(volume: u64, gold_min_volume: u64, diamond_min_volume: u64) -> u8 {
+    if diamond_min_volume > 0 && volume >= diamond_min_volume {
+        DIAMOND
+    } else if gold_min_volume > 0 && volume >= gold_min_volume {
+        GOLD
+    } else {
+        COMMON
+    }
+}
+
+/// Thresholds are valid when the enabled ones ascend
+pub fn is_valid_thresholds(gold_min_volume: u64, diamond_min_volume: u64) -> bool {
+    gold_min_volume == 0 || diamond_min_volume == 0 || gold_min_volume < diamond_min_volume
+}
+
+pub fn label(tier: u8) -> &'static str {
+    match tier {
+        GOLD => "Gold",
+        DIAMOND => "Diamond",
+        _ => "Common",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn volume_normalization() {
+        assert_eq!(normalize_volume(1_500_000, 6), 1_500_000);
+        assert_eq!(normalize_volume(1_500_000_000, 9), 1_500_000);
+        assert_eq!(normalize_volume(1_999, 9), 1);
+        assert_eq!(normalize_volume(15, 0), 15_000_000);
+        assert_eq!(normalize_volume(u64::MAX, 0), u64::MAX);
+        // 10^(255 - 6) does not fit a u64: nothing survives the scaling
+        assert_eq!(normalize_volume(u64::MAX, u8::MAX), 0);
+    }
+
+    #[test]
+    fn normalization_is_monotonic() {
+        for decimals in [0u8, 2, 6, 9, 18] {
+            let mut previous = 0;
+            for amount in (0..1_000_000u64).step_by(997) {
+                let volume = normalize_volume(amount, decimals);
+                assert!(volume >= previous);
+                previous = volume;
+            }
+        }
+    }
+
+    #[test]
+    fn tier_boundaries() {
+        assert_eq!(tier_for(999, 1_000, 10_000), COMMON);
+        assert_eq!(tier_for(1_000, 1_000, 10_000), GOLD);
+        assert_eq!(tier_for(10_000, 1_000, 10_000), DIAMOND);
+        assert_eq!(tier_for(u64::MAX, 1_000, 10_000), DIAMOND);
+    }
+
+    #[test]
+    fn zero_threshold_disables_tier() {
+        assert_eq!(tier_for(u64::MAX, 0, 0), COMMON);
+        assert_eq!(tier_for(5_000, 0, 10_000), COMMON);
+        assert_eq!(tier_for(10_000, 0, 10_000), DIAMOND);
+        assert_eq!(tier_for(u64::MAX, 1_000, 0), GOLD);
+    }
+
+    #[test]
+    fn tier_is_monotonic_for_valid_thresholds() {
+        for (gold, diamond) in [(1, 2), (100, 5_000), (0, 700), (300, 0), (0, 0)] {
+            assert!(is_valid_thresholds(gold, diamond));
+            let mut previous = COMMON;
+            for volume in (0..10_000).step_by(13) {
+                let tier = tier_for(volume, gold, diamond);
+                assert!(tier >= previous);
+                previous = tier;
+            }
+        }
+        assert!(!is_valid_thresholds(5_000, 5_000));
+        assert!(!is_valid_thresholds(5_000, 100));
+    }
+}
@@ -0,0 +1,68 @@
+//! Fixed 24h windows behind the daily XP grant cap and the Pulse daily spend cap.
+//!
+//! A window opens with the first use after the previous one expired, so up to a full
+//! cap may be used at the end of one window and again at the start of the next.
+
+/// Window length in seconds
+pub const LENGTH: i64 = 24 * 60 * 60;
+
+/// Whether the window opened at `start` has expired by `now`
+pub fn is_expired(start: i64, now: i64) -> bool {
+    now.saturating_sub(start) >= LENGTH
+}
+
+/// Add `amount` at `now` to a window opened at `start` with `used` so far, resetting it first
+/// if it expired. Returns the new `(start, used)`, or `None` when the total would pass `cap`
+/// (`None` = no cap).
+pub fn record(start: i64, used: u64, amount: u64, cap: Option<u64>, now: i64) -> Option<(i64, u64)> {
+    let (start, used) = if is_expired(start, now) { (now, 0) } else { (start, used) };
+    let total = used.saturating_add(amount);
+    match cap {
+        Some(cap) if total > cap => None,
+        _ => Some((start, total)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: i64 = 1_700_000_000;
+
+    #[test]
+    fn expires_exactly_at_length() {
+        assert!(!is_expired(T0, T0));
+        assert!(!is_expired(T0, T0 + LENGTH - 1));
+        assert!(is_expired(T0, T0 + LENGTH));
+        // A never-used window (start 0) is expired
+        assert!(is_expired(0, T0));
+    }
+
+    #[test]
+    fn resets_at_the_boundary_not_before() {
+        assert_eq!(record(T0, 90, 10, Some(100), T0 + LENGTH - 1), Some((T0, 100)));
+        assert_eq!(record(T0, 90, 20, Some(100), T0 + LENGTH - 1), None);
+        assert_eq!(record(T0, 90, 20, Some(100), T0 + LENGTH), Some((T0 + LENGTH, 20)));
+    }
+
+    #[test]
+    fn cap_is_inclusive() {
+        assert_eq!(record(T0, 0, 100, Some(100), T0), Some((T0, 100)));
+        assert_eq!(record(T0, 100, 0, Some(100), T0), Some((T0, 100)));
+        assert_eq!(record(T0, 100, 1, Some(100), T0), None);
+        assert_eq!(record(T0, 0, 101, Some(100), T0), None);
+    }
+
+    #[test]
+    fn zero_cap_admits_only_zero() {
+        assert_eq!(record(T0, 0, 1, Some(0), T0), None);
+        assert_eq!(record(T0, 0, 0, Some(0), T0), Some((T0, 0)));
+        assert_eq!(record(T0, 0, 1, Some(0), T0 + LENGTH), None);
+    }
+
+    #[test]
+    fn no_cap_saturates() {
+        assert_eq!(record(T0, u64::MAX - 1, 5, None, T0), Some((T0, u64::MAX)));
+        assert_eq!(record(T0, u64::MAX, u64::MAX, Some(u64::MAX), T0), Some((T0, u64::MAX)));
+    }
+}
@@ -0,0 +1,90 @@
+//! XP rates and multipliers. Accrual itself is a plain `saturating_add`.
+
+/// XP earned per whole token spent, unless a merchant sets its own rate
+pub const DEFAULT_XP_PER_UNIT: u64 = 1;
+
+/// XP for spending `amount` base units of a token with `decimals` decimals at
+/// `xp_per_unit` XP per whole token, rounded down and saturating at `u64::MAX`
+pub fn for_spend(amount: u64, decimals: u8, xp_per_unit: u64) -> u64 {
+    let Some(scale) = 10u128.checked_pow(decimals as u32) else {
+        return 0;
+    };
+    let xp = amount as u128 * xp_per_unit as u128 / scale;
+    u64::try_from(xp).unwrap_or(u64::MAX)
+}
+
+/// Basis points that leave an amount unchanged (1x)
+pub const BPS_BASE: u64 = 10_000;
+
+/// Scale `amount` by `multiplier_bps` (10_000 = 1x), rounded down and saturating at `u64::MAX`
+pub fn boost(amount: u64, multiplier_bps: u16) -> u64 {
+    let xp = amount as u128 * multiplier_bps as u128 / BPS_BASE as u128;
+    u64::try_from(xp).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic sample inputs: every edge value plus a xorshift sequence
+    fn samples() -> impl Iterator<Item = u64> {
+        let edges = [0, 1, 2, 9, 10, 999_999, 1_000_000, u32::MAX as u64, u64::MAX / 2, u64::MAX - 1, u64::MAX];
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let random = core::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        });
+        edges.into_iter().chain(random.take(500))
+    }
+
+    #[test]
+    fn for_spend_rounds_down_per_whole_token() {
+        // 6-decimal stablecoin: 1.999999 tokens at 10 XP/token
+        assert_eq!(for_spend(1_999_999, 6, 10), 19);
+        assert_eq!(for_spend(2_000_000, 6, 10), 20);
+        assert_eq!(for_spend(999_999, 6, 1), 0);
+        assert_eq!(for_spend(5, 0, 3), 15);
+    }
+
+    #[test]
+    fn for_spend_saturates_and_handles_extreme_decimals() {
+        assert_eq!(for_spend(u64::MAX, 0, u64::MAX), u64::MAX);
+        assert_eq!(for_spend(u64::MAX, 38, u64::MAX), 3);
+        // 10^39 overflows u128: no XP rather than a bogus scale
+        assert_eq!(for_spend(u64::MAX, 39, u64::MAX), 0);
+    }
+
+    #[test]
+    fn for_spend_properties() {
+        for amount in samples() {
+            for decimals in [0u8, 6, 9] {
+                let exact = amount as u128 * 7 / 10u128.pow(decimals as u32);
+                assert_eq!(for_spend(amount, decimals, 7) as u128, exact.min(u64::MAX as u128));
+                assert_eq!(for_spend(amount, decimals, 0), 0);
+                // Spending more never earns less
+                assert!(for_spend(amount.saturating_add(1), decimals, 7) >= for_spend(amount, decimals, 7));
+            }
+        }
+    }
+
+    #[test]
+    fn boost_rounds_down_and_saturates() {
+        assert_eq!(boost(100, 15_000), 150);
+        assert_eq!(boost(3, 15_000), 4);
+        assert_eq!(boost(1, 9_999), 0);
+        assert_eq!(boost(u64::MAX, u16::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn boost_properties() {
+        for amount in samples() {
+            assert_eq!(boost(amount, BPS_BASE as u16), amount);
+            assert_eq!(boost(amount, 0), 0);
+            assert!(boost(amount, 20_000) >= amount);
+            assert!(boost(amount, 5_000) <= amount);
+            assert_eq!(boost(amount, 20_000), amount.saturating_mul(2));
+        }
+    }
+}
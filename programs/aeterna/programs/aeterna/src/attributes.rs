@@ -0,0 +1,118 @@
+//! Adapter between the pure `aeterna_rules::attributes` schema and Metaplex Core.
+//! Reserved keys are written exclusively by the program from on-chain state
+//! (SoulStats, Quest). Callers may only supply keys from `ALLOWED_CUSTOM_KEYS`.
+
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    instructions::{UpdatePluginV1Cpi, UpdatePluginV1CpiAccounts, UpdatePluginV1InstructionArgs},
+    types::{Attribute, Attributes, Plugin, PluginType, UpdateAuthority},
+};
+use crate::AeternaError;
+use crate::state::{protocol_config::ProtocolConfig, soul_stats::SoulStats};
+
+pub use aeterna_rules::attributes::{
+    ALLOWED_CUSTOM_KEYS, MAX_CUSTOM_ATTRIBUTES, MAX_KEY_LEN, MAX_VALUE_LEN, RESERVED_KEYS,
+};
+use aeterna_rules::attributes::{self as rules, SoulSnapshot};
+
+/// Validate caller-supplied attributes against the schema.
+/// Rejects reserved keys, unknown keys, duplicates and oversized keys/values.
+pub fn validate_custom(attributes: &[Attribute]) -> Result<()> {
+    let pairs: Vec<(&str, &str)> = attributes
+        .iter()
+        .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+        .collect();
+    rules::validate_custom(&pairs).map_err(AeternaError::from)?;
+    Ok(())
+}
+
+/// Insert `key` or overwrite its value if already present
+pub fn upsert(attributes: &mut Vec<Attribute>, key: &str, value: String) {
+    match attributes.iter_mut().find(|a| a.key == key) {
+        Some(existing) => existing.value = value,
+        None => attributes.push(Attribute { key: key.to_string(), value }),
+    }
+}
+
+fn snapshot<'a>(soul_stats: &SoulStats, config: &'a ProtocolConfig) -> SoulSnapshot<'a> {
+    SoulSnapshot {
+        stage: soul_stats.current_stage,
+        level: soul_stats.level,
+        title: config.title_for(soul_stats.level),
+        wealth_tier: soul_stats.wealth_tier,
+        xp: soul_stats.xp,
+        quests_completed: soul_stats.quests_completed,
+    }
+}
+
+/// Upsert every SoulStats-derived reserved attribute (status, stage, level, title, ...)
+pub fn upsert_soul(attributes: &mut Vec<Attribute>, soul_stats: &SoulStats, config: &ProtocolConfig) {
+    for (key, value) in rules::soul_attributes(&snapshot(soul_stats, config)) {
+        upsert(attributes, key, value);
+    }
+}
+
+/// Whether the asset's `level` or `wealth_tier` attribute no longer matches `soul_stats`
+pub fn is_tier_stale(attributes: &[Attribute], soul_stats: &SoulStats, config: &ProtocolConfig) -> bool {
+    let current = |key: &str| attributes.iter().find(|a| a.key == key).map(|a| a.value.as_str());
+    rules::soul_attributes(&snapshot(soul_stats, config))
+        .into_iter()
+        .filter(|(key, _)| matches!(*key, "level" | "wealth_tier"))
+        .any(|(key, value)| current(key) != Some(value.as_str()))
+}
+
+/// Sort into canonical order: reserved keys first (in `RESERVED_KEYS` order),
+/// then custom keys alphabetically. Keeps asset metadata diff-stable for indexers.
+pub fn sort_canonical(attributes: &mut [Attribute]) {
+    attributes.sort_by(|a, b| rules::canonical_cmp(&a.key, &b.key));
+}
+
+/// Read the current Attributes plugin of a Core asset.
+/// Returns an empty list if the asset has no Attributes plugin yet.
+pub fn load(asset: &AccountInfo) -> Vec<Attribute> {
+    fetch_plugin::<BaseAssetV1, Attributes>(asset, PluginType::Attributes)
+        .map(|(_, attributes, _)| attributes.attribute_list)
+        .unwrap_or_default()
+}
+
+/// Whether `authority` is the asset's own update authority and so can `write` its attributes.
+/// False for collection-managed assets and for passes minted by another authority.
+pub fn is_update_authority(asset: &AccountInfo, authority: &Pubkey) -> Result<bool> {
+    let data = asset.try_borrow_data()?;
+    let asset = BaseAssetV1::from_bytes(&data)?;
+    Ok(asset.update_authority == UpdateAuthority::Address(*authority))
+}
+
+/// Sort `attributes` canonically and replace the asset's Attributes plugin with them.
+/// `authority` must be the asset's update authority.
+pub fn write<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    mut attributes: Vec<Attribute>,
+) -> Result<()> {
+    sort_canonical(&mut attributes);
+
+    UpdatePluginV1Cpi::new(
+        mpl_core_program,
+        UpdatePluginV1CpiAccounts {
+            asset,
+            collection: None,
+            authority: Some(authority),
+            payer,
+            system_program,
+            log_wrapper: None,
+        },
+        UpdatePluginV1InstructionArgs {
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: attributes,
+            }),
+        }
+    ).invoke()?;
+
+    Ok(())
+}
@@ -0,0 +1,468 @@
+use anchor_lang::prelude::*;
+use crate::state::boost_window::BoostScope;
+use crate::state::event::ReentryPolicy;
+use crate::state::protocol_config::LevelTier;
+use crate::state::xp_table::XpTableEntry;
+
+/// Emitted whenever a Soul's XP crosses a level threshold from the `ProtocolConfig` ladder
+#[event]
+pub struct LevelUp {
+    pub asset: Pubkey,
+    pub previous_level: u8,
+    pub new_level: u8,
+    pub title: String,
+    pub xp: u64,
+}
+
+/// Emitted when added trading volume moves a Soul into a different wealth tier
+#[event]
+pub struct WealthTierChanged {
+    pub asset: Pubkey,
+    pub previous_tier: u8,
+    pub new_tier: u8,
+    pub trading_volume: u64,
+}
+
+/// Emitted by `initialize_pass` once the Core asset and SoulStats exist
+#[event]
+pub struct PassMinted {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub event: Pubkey,
+    pub soul_stats: Pubkey,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Emitted by `register_pulse_wallet`. `previous_wallet` is `None` on first link.
+#[event]
+pub struct PulseLinked {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub previous_wallet: Option<Pubkey>,
+    pub pulse_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `unregister_pulse_wallet` when the link is closed
+#[event]
+pub struct PulseUnlinked {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub pulse_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `grant_xp` with the full before/after ledger values
+#[event]
+pub struct XpGranted {
+    pub asset: Pubkey,
+    pub receipt_id: [u8; 32],
+    pub action: u8,
+    pub count: u32,
+    pub xp_amount: u64,
+    pub xp_before: u64,
+    pub xp_after: u64,
+    pub trading_volume_before: u64,
+    pub trading_volume_after: u64,
+    pub quests_completed_before: u32,
+    pub quests_completed_after: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `pulse_pay` with the full before/after ledger values
+#[event]
+pub struct PulsePayment {
+    pub asset: Pubkey,
+    pub pulse_wallet: Pubkey,
+    pub merchant: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub xp_awarded: u64,
+    pub xp_before: u64,
+    pub xp_after: u64,
+    pub trading_volume_before: u64,
+    pub trading_volume_after: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `complete_quest` with the full before/after ledger values. `xp_reward` includes any boost.
+#[event]
+pub struct QuestCompleted {
+    pub asset: Pubkey,
+    pub quest: Pubkey,
+    pub event: Pubkey,
+    pub xp_reward: u64,
+    pub xp_before: u64,
+    pub xp_after: u64,
+    pub quests_completed_before: u32,
+    pub quests_completed_after: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `evolve_soul`
+#[event]
+pub struct SoulEvolved {
+    pub asset: Pubkey,
+    pub previous_stage: u8,
+    pub new_stage: u8,
+    pub xp: u64,
+    pub new_uri: Option<String>,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_event`
+#[event]
+pub struct EventCreated {
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_quest`
+#[event]
+pub struct QuestCreated {
+    pub quest: Pubkey,
+    pub event: Pubkey,
+    pub name: String,
+    pub xp_reward: u64,
+    pub min_wealth_tier: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `claim_achievement` after the soulbound badge is minted
+#[event]
+pub struct AchievementClaimed {
+    pub achievement: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub badge: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `record_attendance` when a soul's attendance proof is minted
+#[event]
+pub struct AttendanceRecorded {
+    pub event: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub proof: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `redeem_reward` once points are deducted and the receipt is written
+#[event]
+pub struct RewardRedeemed {
+    pub reward: Pubkey,
+    pub redemption: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub serial: u32,
+    pub cost: u64,
+    pub points_before: u64,
+    pub points_after: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `fulfill_redemption` when staff hand the reward over
+#[event]
+pub struct RedemptionFulfilled {
+    pub reward: Pubkey,
+    pub redemption: Pubkey,
+    pub asset: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_airdrop`
+#[event]
+pub struct AirdropCreated {
+    pub airdrop: Pubkey,
+    pub name: String,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub min_stage: u8,
+    pub num_leaves: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `claim_airdrop` after the tokens are transferred
+#[event]
+pub struct AirdropClaimed {
+    pub airdrop: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `sweep_airdrop` with the unclaimed supply it reclaimed
+#[event]
+pub struct AirdropSwept {
+    pub airdrop: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub claimed_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `check_in` at the venue gate
+#[event]
+pub struct CheckedIn {
+    pub event: Pubkey,
+    pub asset: Pubkey,
+    pub entries: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `check_out` at the venue gate
+#[event]
+pub struct CheckedOut {
+    pub event: Pubkey,
+    pub asset: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted whenever a merchant is created or its rate, fee or status changes
+#[event]
+pub struct MerchantUpdated {
+    pub merchant: Pubkey,
+    pub event: Pubkey,
+    pub name: String,
+    pub payout: Pubkey,
+    pub xp_per_unit: u64,
+    pub fee_bps: u16,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted alongside the XP event whenever a live `BoostWindow` multiplied the award
+#[event]
+pub struct XpBoosted {
+    pub boost: Pubkey,
+    pub asset: Pubkey,
+    pub base_xp: u64,
+    pub boosted_xp: u64,
+    pub multiplier_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted by `claim_action_xp` with the matched program and before/after XP
+#[event]
+pub struct ActionXpClaimed {
+    pub action: Pubkey,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub program: Pubkey,
+    pub xp_reward: u64,
+    pub xp_before: u64,
+    pub xp_after: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `initialize_config` and `update_level_table` with the full ladder
+#[event]
+pub struct LevelTableUpdated {
+    pub levels: Vec<LevelTier>,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_wealth_tiers`
+#[event]
+pub struct WealthTiersUpdated {
+    pub gold_min_volume: u64,
+    pub diamond_min_volume: u64,
+    pub ascend_min_wealth_tier: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_xp_caps`
+#[event]
+pub struct XpCapsUpdated {
+    pub max_grant_xp: u64,
+    pub daily_xp_cap: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_payment_config`
+#[event]
+pub struct PaymentConfigUpdated {
+    pub stable_mint: Pubkey,
+    pub max_xp_per_unit: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `initialize_xp_table` and `update_xp_table` with the full table
+#[event]
+pub struct XpTableUpdated {
+    pub entries: Vec<XpTableEntry>,
+    pub timestamp: i64,
+}
+
+/// Emitted by `migrate_account`
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub previous_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_leaderboard`
+#[event]
+pub struct LeaderboardCreated {
+    pub leaderboard: Pubkey,
+    pub event: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `initialize_badge_collection`
+#[event]
+pub struct BadgeCollectionInitialized {
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_achievement`
+#[event]
+pub struct AchievementCreated {
+    pub achievement: Pubkey,
+    pub name: String,
+    pub event: Option<Pubkey>,
+    pub max_supply: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `enable_attendance`
+#[event]
+pub struct AttendanceEnabled {
+    pub event: Pubkey,
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `set_reentry_policy`
+#[event]
+pub struct ReentryPolicyUpdated {
+    pub event: Pubkey,
+    pub policy: ReentryPolicy,
+    pub cooldown: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_reward`
+#[event]
+pub struct RewardCreated {
+    pub reward: Pubkey,
+    pub event: Pubkey,
+    pub name: String,
+    pub cost: u64,
+    pub stock: Option<u32>,
+    pub min_stage: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_reward` with the reward's new cost, stock and status
+#[event]
+pub struct RewardUpdated {
+    pub reward: Pubkey,
+    pub event: Pubkey,
+    pub cost: u64,
+    pub stock: Option<u32>,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted by `set_pulse_limits`
+#[event]
+pub struct PulseLimitsUpdated {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub per_tx_cap: u64,
+    pub daily_cap: u64,
+    pub allowlist: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted by `pulse_spend`
+#[event]
+pub struct PulseSpent {
+    pub asset: Pubkey,
+    pub pulse_wallet: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `pulse_withdraw`
+#[event]
+pub struct PulseWithdrawn {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `initialize_treasury_vault`
+#[event]
+pub struct TreasuryVaultInitialized {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `withdraw_treasury`
+#[event]
+pub struct TreasuryWithdrawn {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_boost_window`
+#[event]
+pub struct BoostWindowCreated {
+    pub boost: Pubkey,
+    pub event: Pubkey,
+    pub name: String,
+    pub start: i64,
+    pub end: i64,
+    pub multiplier_bps: u16,
+    pub scope: BoostScope,
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_boost_window`
+#[event]
+pub struct BoostWindowClosed {
+    pub boost: Pubkey,
+    pub event: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_grant_receipt`
+#[event]
+pub struct GrantReceiptClosed {
+    pub receipt: Pubkey,
+    pub receipt_id: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted whenever an action type is created or its XP entry, programs, cooldown or status changes
+#[event]
+pub struct ActionTypeUpdated {
+    pub action: Pubkey,
+    pub name: String,
+    pub xp_action: u8,
+    pub programs: Vec<Pubkey>,
+    pub cooldown: u32,
+    pub active: bool,
+    pub timestamp: i64,
+}
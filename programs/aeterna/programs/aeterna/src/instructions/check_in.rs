@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use mpl_core::ID as CORE_PROGRAM_ID;
+use crate::instructions::record_attendance::ProofMint;
+use crate::state::attendance_record::AttendanceRecord;
+use crate::state::event::{Event, ReentryPolicy};
+use crate::state::event_participation::EventParticipation;
+use crate::state::soul_stats::SoulStats;
+use crate::events::CheckedIn;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    /// The Event Authority (gate scanner) — must match event.authority
+    pub authority: Signer<'info>,
+
+    /// Pays for the participation record on a soul's first interaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.active,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The pass being scanned — must be an AETERNA pass
+    /// CHECK: Bound to soul_stats below
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    /// The soul's record at this event — created on its first interaction
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EventParticipation::SIZE,
+        seeds = [b"participation", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub participation: Account<'info, EventParticipation>,
+
+    // ── Attendance proof: required on a soul's first entry when the event has proofs
+    // enabled, and skipped if `record_attendance` already minted one ──────────────
+    /// The pass owner, who receives the proof
+    /// CHECK: Verified against the asset's owner when the proof is minted
+    pub owner: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AttendanceRecord::SIZE,
+        seeds = [b"attendance", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub attendance: Option<Account<'info, AttendanceRecord>>,
+
+    /// Fresh keypair for the attendance proof asset
+    #[account(mut)]
+    pub proof: Option<Signer<'info>>,
+
+    /// CHECK: Must be the event's attendance collection; validated by Core during CPI
+    #[account(
+        mut,
+        address = event.attendance_collection @ AeternaError::AttendanceNotEnabled
+    )]
+    pub attendance_collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: PDA that is every attendance collection's update authority; signs the mint
+    #[account(seeds = [b"attendance_authority"], bump)]
+    pub attendance_authority: Option<UncheckedAccount<'info>>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CheckIn>) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let participation = &mut ctx.accounts.participation;
+    let now = Clock::get()?.unix_timestamp;
+
+    participation.visit(event.key(), ctx.accounts.asset.key(), ctx.bumps.participation, now);
+
+    // ── A pass can only be inside once — a shared QR screenshot fails here ───
+    require!(!participation.inside, AeternaError::AlreadyCheckedIn);
+
+    if participation.entries > 0 {
+        match event.reentry_policy {
+            ReentryPolicy::Unlimited => {}
+            ReentryPolicy::NoReentry => return err!(AeternaError::ReentryNotAllowed),
+            ReentryPolicy::Cooldown => require!(
+                now.saturating_sub(participation.last_check_out) >= event.reentry_cooldown as i64,
+                AeternaError::ReentryCooldown
+            ),
+        }
+    }
+
+    let first_entry = participation.entries == 0;
+    participation.inside = true;
+    participation.last_check_in = now;
+    participation.entries = participation.entries.saturating_add(1);
+
+    emit!(CheckedIn {
+        event: event.key(),
+        asset: participation.asset,
+        entries: participation.entries,
+        timestamp: now,
+    });
+
+    msg!("Checked in at '{}': {} (entry #{})", event.name, participation.asset, participation.entries);
+
+    if first_entry && event.attendance_enabled() {
+        mint_proof(ctx, now)?;
+    }
+    Ok(())
+}
+
+/// Mint the attendance proof for a first entry, unless `record_attendance` already did
+fn mint_proof(ctx: Context<CheckIn>, now: i64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (
+        Some(owner),
+        Some(attendance),
+        Some(proof),
+        Some(attendance_collection),
+        Some(attendance_authority),
+        Some(mpl_core_program),
+    ) = (
+        accounts.owner.as_ref(),
+        accounts.attendance.as_mut(),
+        accounts.proof.as_ref(),
+        accounts.attendance_collection.as_ref(),
+        accounts.attendance_authority.as_ref(),
+        accounts.mpl_core_program.as_ref(),
+    ) else {
+        return err!(AeternaError::AttendanceProofRequired);
+    };
+    if attendance.proof != Pubkey::default() {
+        return Ok(());
+    }
+
+    ProofMint {
+        event: &accounts.event,
+        asset: &accounts.asset,
+        owner,
+        payer: &accounts.payer,
+        proof,
+        attendance_collection,
+        attendance_authority,
+        attendance_authority_bump: ctx.bumps.attendance_authority,
+        mpl_core_program,
+        system_program: &accounts.system_program,
+    }
+    .mint(attendance, ctx.bumps.attendance, now)
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::event::Event;
+use crate::state::event_participation::EventParticipation;
+use crate::events::CheckedOut;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct CheckOut<'info> {
+    /// The Event Authority (gate scanner) — must match event.authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"participation", event.key().as_ref(), participation.asset.as_ref()],
+        bump = participation.bump,
+    )]
+    pub participation: Account<'info, EventParticipation>,
+}
+
+pub fn handler(ctx: Context<CheckOut>) -> Result<()> {
+    let participation = &mut ctx.accounts.participation;
+    require!(participation.inside, AeternaError::NotCheckedIn);
+
+    let now = Clock::get()?.unix_timestamp;
+    participation.inside = false;
+    participation.last_check_out = now;
+    participation.last_seen = now;
+
+    emit!(CheckedOut {
+        event: participation.event,
+        asset: participation.asset,
+        timestamp: now,
+    });
+
+    msg!("Checked out of '{}': {}", ctx.accounts.event.name, participation.asset);
+    Ok(())
+}
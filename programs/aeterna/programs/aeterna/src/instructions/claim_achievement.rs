@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as CORE_PROGRAM_ID,
+    instructions::{CreateV1Cpi, CreateV1CpiAccounts, CreateV1InstructionArgs},
+    types::{DataState, PermanentFreezeDelegate, Plugin, PluginAuthorityPair},
+};
+use crate::state::achievement::{Achievement, AchievementClaim};
+use crate::state::event_participation::EventParticipation;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::soul_stats::SoulStats;
+use crate::events::AchievementClaimed;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct ClaimAchievement<'info> {
+    /// The pass owner — receives the badge and pays for it
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The AETERNA Pass that earned the achievement
+    /// CHECK: Owner verified in handler via BaseAssetV1 deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    #[account(
+        mut,
+        seeds = [b"achievement", achievement.name.as_bytes()],
+        bump = achievement.bump,
+    )]
+    pub achievement: Account<'info, Achievement>,
+
+    /// Required when the achievement is scoped to an event
+    #[account(
+        seeds = [b"participation", participation.event.as_ref(), asset.key().as_ref()],
+        bump = participation.bump,
+    )]
+    pub participation: Option<Account<'info, EventParticipation>>,
+
+    /// ── DEDUP: one claim per (achievement, asset) ──
+    #[account(
+        init,
+        payer = owner,
+        space = AchievementClaim::SIZE,
+        seeds = [b"achievement_claim", achievement.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, AchievementClaim>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Fresh keypair for the soulbound badge asset
+    #[account(mut)]
+    pub badge: Signer<'info>,
+
+    /// CHECK: Must be the configured badge collection; validated by Core during CPI
+    #[account(
+        mut,
+        address = config.badge_collection @ AeternaError::BadgeCollectionNotSet
+    )]
+    pub badge_collection: UncheckedAccount<'info>,
+
+    /// CHECK: PDA that is the badge collection's update authority; signs the mint
+    #[account(seeds = [b"badge_authority"], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimAchievement>) -> Result<()> {
+    let achievement = &ctx.accounts.achievement;
+
+    // ── Only the pass owner may claim, and the badge goes to them ─────────────
+    {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?;
+        require_keys_eq!(asset.owner, ctx.accounts.owner.key(), AeternaError::Unauthorized);
+    }
+
+    // ── Event-scoped achievements need the soul's participation at that event ─
+    let participation = ctx.accounts.participation.as_deref();
+    if let Some(event) = achievement.event {
+        require!(
+            participation.map_or(false, |p| p.event == event),
+            AeternaError::ParticipationRequired
+        );
+    }
+
+    require!(
+        achievement.is_met(&ctx.accounts.soul_stats, participation),
+        AeternaError::AchievementCriteriaNotMet
+    );
+    require!(
+        achievement.max_supply == 0 || achievement.claimed < achievement.max_supply,
+        AeternaError::AchievementSoldOut
+    );
+
+    // ── Mint a frozen (non-transferable) badge into the badge collection ─────
+    let signer_seeds: &[&[u8]] = &[b"badge_authority", &[ctx.bumps.badge_authority]];
+    CreateV1Cpi::new(
+        &ctx.accounts.mpl_core_program,
+        CreateV1CpiAccounts {
+            asset: &ctx.accounts.badge,
+            collection: Some(&ctx.accounts.badge_collection),
+            authority: Some(&ctx.accounts.badge_authority),
+            payer: &ctx.accounts.owner,
+            owner: Some(&ctx.accounts.owner),
+            update_authority: None,
+            system_program: &ctx.accounts.system_program,
+            log_wrapper: None,
+        },
+        CreateV1InstructionArgs {
+            name: achievement.name.clone(),
+            uri: achievement.uri.clone(),
+            plugins: Some(vec![PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+                authority: None,
+            }]),
+            data_state: DataState::AccountState,
+        }
+    ).invoke_signed(&[signer_seeds])?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.claim;
+    claim.achievement = ctx.accounts.achievement.key();
+    claim.asset = ctx.accounts.asset.key();
+    claim.badge = ctx.accounts.badge.key();
+    claim.claimed_at = now;
+    claim.bump = ctx.bumps.claim;
+    claim.version = AchievementClaim::VERSION;
+
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.claimed = achievement.claimed.saturating_add(1);
+
+    emit!(AchievementClaimed {
+        achievement: achievement.key(),
+        asset: claim.asset,
+        owner: ctx.accounts.owner.key(),
+        badge: claim.badge,
+        timestamp: now,
+    });
+
+    msg!("Achievement '{}' claimed by Asset: {}", achievement.name, claim.asset);
+    Ok(())
+}
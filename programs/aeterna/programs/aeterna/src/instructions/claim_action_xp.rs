@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use crate::instructions::pulse_vault::asset_owner;
+use crate::state::action_type::{ActionClaim, ActionType};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::soul_stats::SoulStats;
+use crate::state::xp_table::XpTable;
+use crate::events::{ActionXpClaimed, LevelUp};
+use crate::AeternaError;
+
+/// Owner-signed XP for an on-chain action in the same transaction — no backend involved.
+/// The instruction immediately before this one must target one of the action's allowlisted
+/// programs and be signed by the pass owner. Only one claim is allowed per transaction, and
+/// it must be a top-level instruction so a wrapper program cannot repeat it via CPI.
+#[derive(Accounts)]
+pub struct ClaimActionXp<'info> {
+    /// The pass owner — must also sign the allowlisted instruction
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The AETERNA Pass being credited
+    /// CHECK: Owner verified in handler via BaseAssetV1 deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    /// Protocol config — source of the level ladder and XP caps
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Prices the claim through the action's `xp_action` entry
+    #[account(seeds = [b"xp_table"], bump = xp_table.bump)]
+    pub xp_table: Account<'info, XpTable>,
+
+    #[account(
+        seeds = [b"action_type", action.name.as_bytes()],
+        bump = action.bump,
+        constraint = action.active @ AeternaError::ActionInactive
+    )]
+    pub action: Account<'info, ActionType>,
+
+    /// Cooldown tracking for this (action, soul) pair — created on the first claim
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ActionClaim::SIZE,
+        seeds = [b"action_claim", action.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub action_claim: Account<'info, ActionClaim>,
+
+    /// CHECK: Validated via address constraint against the instructions sysvar ID
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimActionXp>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require_keys_eq!(asset_owner(&ctx.accounts.asset)?, owner, AeternaError::Unauthorized);
+    require!(
+        aeterna_rules::stage::can_earn_xp(ctx.accounts.soul_stats.current_stage),
+        AeternaError::NotActivated
+    );
+
+    // ── This claim must be the transaction's only one, called at the top level ─
+    let action = &ctx.accounts.action;
+    let sysvar = ctx.accounts.instructions.to_account_info();
+    let current = load_current_index_checked(&sysvar)? as usize;
+    let is_claim = |ix: &anchor_lang::solana_program::instruction::Instruction| {
+        ix.program_id == crate::ID && ix.data.starts_with(&crate::instruction::ClaimActionXp::DISCRIMINATOR)
+    };
+    require!(
+        is_claim(&load_instruction_at_checked(current, &sysvar)?),
+        AeternaError::DuplicateActionClaim
+    );
+    let claims = (0..)
+        .map_while(|index| load_instruction_at_checked(index, &sysvar).ok())
+        .filter(|ix| is_claim(ix))
+        .count();
+    require!(claims == 1, AeternaError::DuplicateActionClaim);
+
+    // ── The action must be the instruction right before this one ────────────
+    let program = current
+        .checked_sub(1)
+        .and_then(|index| load_instruction_at_checked(index, &sysvar).ok())
+        .filter(|ix| action.matches(ix, &owner))
+        .map(|ix| ix.program_id)
+        .ok_or(AeternaError::ActionNotFound)?;
+
+    // ── Cooldown — a fresh claim account has never been claimed ──────────────
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.action_claim;
+    if claim.count > 0 {
+        require!(
+            now.saturating_sub(claim.last_claimed_at) >= action.cooldown as i64,
+            AeternaError::ActionCooldown
+        );
+    }
+    claim.action = action.key();
+    claim.asset = ctx.accounts.asset.key();
+    claim.count = claim.count.saturating_add(1);
+    claim.last_claimed_at = now;
+    claim.bump = ctx.bumps.action_claim;
+    claim.version = ActionClaim::VERSION;
+
+    let xp_reward = ctx
+        .accounts
+        .xp_table
+        .entry(action.xp_action)
+        .ok_or(AeternaError::UnknownAction)?
+        .xp_for(1);
+
+    let soul_stats = &mut ctx.accounts.soul_stats;
+    soul_stats.record_grant(xp_reward, &ctx.accounts.config, now)?;
+    let xp_before = soul_stats.xp;
+    soul_stats.earn(xp_reward);
+
+    // Core attributes are written by the asset's update authority, not the owner,
+    // so they catch up on the soul's next `complete_quest` / `evolve_soul`, or on its next
+    // `grant_xp` when the backend is the update authority.
+    let config = &ctx.accounts.config;
+    let level_change = soul_stats.refresh_level(config);
+
+    emit!(ActionXpClaimed {
+        action: action.key(),
+        asset: soul_stats.asset,
+        owner,
+        program,
+        xp_reward,
+        xp_before,
+        xp_after: soul_stats.xp,
+        timestamp: now,
+    });
+
+    if let Some(previous_level) = level_change {
+        emit!(LevelUp {
+            asset: soul_stats.asset,
+            previous_level,
+            new_level: soul_stats.level,
+            title: config.title_for(soul_stats.level).to_string(),
+            xp: soul_stats.xp,
+        });
+    }
+
+    msg!("Action XP: '{}' via {} | +{} XP", action.name, program, xp_reward);
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::airdrop::Airdrop;
+use crate::state::soul_stats::SoulStats;
+use crate::events::AirdropClaimed;
+use crate::merkle;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    /// The pass owner
+    pub owner: Signer<'info>,
+
+    /// The AETERNA Pass named in the leaf
+    /// CHECK: Owner verified in handler via BaseAssetV1 deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    #[account(
+        mut,
+        seeds = [b"airdrop", airdrop.name.as_bytes()],
+        bump = airdrop.bump,
+        has_one = vault,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Any token account for the airdrop's mint
+    #[account(mut, token::mint = airdrop.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimAirdropArgs {
+    pub index: u32,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn handler(ctx: Context<ClaimAirdrop>, args: ClaimAirdropArgs) -> Result<()> {
+    {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?;
+        require_keys_eq!(asset.owner, ctx.accounts.owner.key(), AeternaError::Unauthorized);
+    }
+
+    // ── Stage gate — "Ascended: VIP Access, Airdrops" ────────────────────────
+    let airdrop = &ctx.accounts.airdrop;
+    require!(
+        ctx.accounts.soul_stats.current_stage >= airdrop.min_stage,
+        AeternaError::StageTooLow
+    );
+
+    require!(!airdrop.swept, AeternaError::AirdropClosed);
+    require!(args.index < airdrop.num_leaves, AeternaError::InvalidMerkleProof);
+    require!(!airdrop.is_claimed(args.index), AeternaError::AirdropAlreadyClaimed);
+
+    let leaf = merkle::leaf(args.index, &ctx.accounts.asset.key(), args.amount);
+    require!(
+        merkle::verify(&args.proof, &airdrop.merkle_root, leaf),
+        AeternaError::InvalidMerkleProof
+    );
+
+    let signer_seeds: &[&[u8]] = &[b"airdrop", airdrop.name.as_bytes(), &[airdrop.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.airdrop.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        args.amount,
+    )?;
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.set_claimed(args.index);
+    airdrop.claimed_count = airdrop.claimed_count.saturating_add(1);
+    airdrop.claimed_amount = airdrop.claimed_amount.saturating_add(args.amount);
+
+    emit!(AirdropClaimed {
+        airdrop: airdrop.key(),
+        asset: ctx.accounts.asset.key(),
+        owner: ctx.accounts.owner.key(),
+        index: args.index,
+        amount: args.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Airdrop '{}' leaf {} claimed: {} tokens", airdrop.name, args.index, args.amount);
+    Ok(())
+}
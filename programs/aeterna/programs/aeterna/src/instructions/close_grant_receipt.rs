@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::instructions::grant_xp::BACKEND_AUTHORITY;
+use crate::state::grant_receipt::GrantReceipt;
+use crate::events::GrantReceiptClosed;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct CloseGrantReceipt<'info> {
+    /// The backend authority that paid for the receipt — receives the rent back
+    #[account(
+        mut,
+        constraint = authority.key() == BACKEND_AUTHORITY @ AeternaError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"grant_receipt", receipt.receipt_id.as_ref()],
+        bump = receipt.bump,
+    )]
+    pub receipt: Account<'info, GrantReceipt>,
+}
+
+pub fn handler(ctx: Context<CloseGrantReceipt>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.receipt.is_expired(now), AeternaError::ReceiptRetained);
+
+    emit!(GrantReceiptClosed {
+        receipt: ctx.accounts.receipt.key(),
+        receipt_id: ctx.accounts.receipt.receipt_id,
+        timestamp: now,
+    });
+
+    msg!("Grant Receipt Closed: {}", ctx.accounts.receipt.key());
+    Ok(())
+}
@@ -3,11 +3,13 @@ use crate::state::event::Event;
 use crate::state::quest::Quest;
 use crate::state::soul_stats::SoulStats;
 use crate::state::completion_record::CompletionRecord;
-use mpl_core::{
-    ID as CORE_PROGRAM_ID,
-    instructions::{UpdatePluginV1Cpi, UpdatePluginV1CpiAccounts, UpdatePluginV1InstructionArgs},
-    types::{Attribute, Plugin, Attributes},
-};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::event_leaderboard::EventLeaderboard;
+use crate::state::event_participation::EventParticipation;
+use crate::state::boost_window::BoostWindow;
+use crate::attributes;
+use crate::events::{LevelUp, QuestCompleted, XpBoosted};
+use mpl_core::ID as CORE_PROGRAM_ID;
 
 #[derive(Accounts)]
 pub struct CompleteQuest<'info> {
@@ -31,7 +33,7 @@ pub struct CompleteQuest<'info> {
     #[account(
         seeds = [b"event", event.name.as_bytes()],
         bump = event.bump,
-        constraint = event.active == true,
+        constraint = event.active,
         constraint = event.authority == authority.key() @ crate::AeternaError::Unauthorized
     )]
     pub event: Account<'info, Event>,
@@ -56,6 +58,10 @@ pub struct CompleteQuest<'info> {
     )]
     pub soul_stats: Account<'info, SoulStats>,
 
+    /// Protocol config — source of the level ladder and XP caps
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
     /// ── DEDUP: this account will fail to init if already completed (C2 fix) ──
     /// Seeds: ["completion", quest.key(), asset.key()]
     /// `init` means a second call for the same (quest, asset) fails with AccountAlreadyInUse
@@ -68,6 +74,32 @@ pub struct CompleteQuest<'info> {
     )]
     pub completion_record: Account<'info, CompletionRecord>,
 
+    /// The soul's record at this event — created on its first interaction
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EventParticipation::SIZE,
+        seeds = [b"participation", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub participation: Account<'info, EventParticipation>,
+
+    /// This event's leaderboard address — always required, updated in place once created
+    /// CHECK: PDA-constrained; deserialized by `EventLeaderboard::record_if_created`
+    #[account(
+        mut,
+        seeds = [b"leaderboard", event.key().as_ref()],
+        bump,
+    )]
+    pub leaderboard: UncheckedAccount<'info>,
+
+    /// Optional boost window at this event — multiplies the reward while live and in scope
+    #[account(
+        seeds = [b"boost", event.key().as_ref(), boost.name.as_bytes()],
+        bump = boost.bump,
+    )]
+    pub boost: Option<Account<'info, BoostWindow>>,
+
     /// The Metaplex Core Program
     /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
     #[account(address = CORE_PROGRAM_ID)]
@@ -78,70 +110,125 @@ pub struct CompleteQuest<'info> {
 
 pub fn handler(ctx: Context<CompleteQuest>) -> Result<()> {
     let quest = &ctx.accounts.quest;
+    let config = &ctx.accounts.config;
     let soul_stats = &mut ctx.accounts.soul_stats;
     let asset_info = &ctx.accounts.asset;
     let recipient = &ctx.accounts.recipient;
 
     // ── A6: Verify Asset Ownership ───────────────────────────────────────────
-    let asset_data = asset_info.try_borrow_data()?;
-    let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?;
-    require_keys_eq!(asset.owner, recipient.key(), crate::AeternaError::Unauthorized);
+    // Scoped so the data borrow is released before the Core CPI below
+    {
+        let asset_data = asset_info.try_borrow_data()?;
+        let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?;
+        require_keys_eq!(asset.owner, recipient.key(), crate::AeternaError::Unauthorized);
+    }
 
     // ── A4: Gate XP earn — Dormant souls (stage 0) cannot earn XP ────────────
     // Per mechanics_review.md: "Dormant: Cannot earn XP"
     require!(
-        soul_stats.current_stage >= 1,
+        aeterna_rules::stage::can_earn_xp(soul_stats.current_stage),
         crate::AeternaError::NotActivated
     );
 
+    require!(
+        soul_stats.wealth_tier >= quest.min_wealth_tier,
+        crate::AeternaError::WealthTierTooLow
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let boost = ctx
+        .accounts
+        .boost
+        .as_ref()
+        .and_then(|boost| boost.quest_multiplier(&quest.key(), now).map(|bps| (boost.key(), bps)));
+    let xp_reward = match boost {
+        Some((_, multiplier_bps)) => aeterna_rules::xp::boost(quest.xp_reward, multiplier_bps),
+        None => quest.xp_reward,
+    };
+
     msg!("Completing Quest '{}' for Asset: {}", quest.name, ctx.accounts.asset.key());
-    msg!("XP before: {} | Reward: {}", soul_stats.xp, quest.xp_reward);
+    msg!("XP before: {} | Reward: {}", soul_stats.xp, xp_reward);
+
+    let xp_before = soul_stats.xp;
+    let quests_completed_before = soul_stats.quests_completed;
 
     // ✅ Real XP Update
-    soul_stats.xp = soul_stats.xp.saturating_add(quest.xp_reward);
+    soul_stats.record_grant(xp_reward, config, now)?;
+    soul_stats.earn(xp_reward);
     soul_stats.quests_completed = soul_stats.quests_completed.saturating_add(1);
+    let level_change = soul_stats.refresh_level(config);
 
     msg!("XP after: {}", soul_stats.xp);
 
+    // ── Event-scoped tracking, separate from lifetime totals ──────────────────
+    let participation = &mut ctx.accounts.participation;
+    participation.visit(
+        ctx.accounts.event.key(),
+        asset_info.key(),
+        ctx.bumps.participation,
+        now,
+    );
+    participation.add_xp(xp_reward);
+    participation.quests_completed = participation.quests_completed.saturating_add(1);
+
+    EventLeaderboard::record_if_created(&ctx.accounts.leaderboard, asset_info.key(), participation.event_xp)?;
+
     // ── Record the completion (dedup PDA already initialized above) ───────────
     let completion_record = &mut ctx.accounts.completion_record;
     completion_record.quest = quest.key();
     completion_record.asset = ctx.accounts.asset.key();
-    completion_record.completed_at = Clock::get()?.unix_timestamp;
+    completion_record.completed_at = now;
     completion_record.bump = ctx.bumps.completion_record;
+    completion_record.version = CompletionRecord::VERSION;
+
+    // Update Metaplex Core attributes so indexers (Tensor, ME) see the new XP.
+    // Merge into the existing list so stage/status/custom traits are preserved.
+    let title = config.title_for(soul_stats.level).to_string();
+    let mut updated_attributes = attributes::load(asset_info);
+    attributes::upsert_soul(&mut updated_attributes, soul_stats, config);
+    attributes::upsert(&mut updated_attributes, "last_quest", quest.name.clone());
 
-    // Update Metaplex Core attributes so indexers (Tensor, ME) see the new XP
-    let updated_attributes = vec![
-        Attribute {
-            key: "xp".to_string(),
-            value: soul_stats.xp.to_string(),
-        },
-        Attribute {
-            key: "last_quest".to_string(),
-            value: quest.name.clone(),
-        },
-        Attribute {
-            key: "quests_completed".to_string(),
-            value: soul_stats.quests_completed.to_string(),
-        },
-    ];
-
-    UpdatePluginV1Cpi::new(
+    attributes::write(
         &ctx.accounts.mpl_core_program,
-        UpdatePluginV1CpiAccounts {
-            asset: &ctx.accounts.asset,
-            collection: None,
-            authority: Some(&ctx.accounts.authority),
-            payer: &ctx.accounts.payer,
-            system_program: &ctx.accounts.system_program,
-            log_wrapper: None,
-        },
-        UpdatePluginV1InstructionArgs {
-            plugin: Plugin::Attributes(Attributes {
-                attribute_list: updated_attributes,
-            }),
-        }
-    ).invoke()?;
+        asset_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        updated_attributes,
+    )?;
+
+    emit!(QuestCompleted {
+        asset: asset_info.key(),
+        quest: quest.key(),
+        event: ctx.accounts.event.key(),
+        xp_reward,
+        xp_before,
+        xp_after: soul_stats.xp,
+        quests_completed_before,
+        quests_completed_after: soul_stats.quests_completed,
+        timestamp: now,
+    });
+
+    if let Some((boost, multiplier_bps)) = boost {
+        emit!(XpBoosted {
+            boost,
+            asset: asset_info.key(),
+            base_xp: quest.xp_reward,
+            boosted_xp: xp_reward,
+            multiplier_bps,
+            timestamp: now,
+        });
+    }
+
+    if let Some(previous_level) = level_change {
+        emit!(LevelUp {
+            asset: asset_info.key(),
+            previous_level,
+            new_level: soul_stats.level,
+            title,
+            xp: soul_stats.xp,
+        });
+    }
 
     msg!("Quest Complete. New XP Total: {}", soul_stats.xp);
     Ok(())
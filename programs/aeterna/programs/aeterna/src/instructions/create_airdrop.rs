@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::airdrop::Airdrop;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::{AirdropCreated, AirdropSwept};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+#[instruction(args: AirdropArgs)]
+pub struct CreateAirdrop<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Airdrop::space(args.num_leaves),
+        seeds = [b"airdrop", args.name.as_bytes()],
+        bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Funded after creation by transferring `mint` tokens in
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = airdrop,
+        seeds = [b"airdrop_vault", airdrop.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepAirdrop<'info> {
+    /// Protocol admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"airdrop", airdrop.name.as_bytes()],
+        bump = airdrop.bump,
+        has_one = vault,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Receives the unclaimed supply
+    #[account(mut, token::mint = airdrop.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AirdropArgs {
+    pub name: String,
+    pub merkle_root: [u8; 32],
+    pub num_leaves: u32,
+    pub min_stage: u8,
+}
+
+pub fn handler(ctx: Context<CreateAirdrop>, args: AirdropArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidAirdrop
+    );
+    require!(
+        args.num_leaves > 0 && args.num_leaves <= Airdrop::MAX_LEAVES,
+        AeternaError::InvalidAirdrop
+    );
+    require!(
+        args.min_stage <= aeterna_rules::stage::ASCENDED,
+        AeternaError::InvalidStage
+    );
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.authority = ctx.accounts.authority.key();
+    airdrop.name = args.name;
+    airdrop.mint = ctx.accounts.mint.key();
+    airdrop.vault = ctx.accounts.vault.key();
+    airdrop.merkle_root = args.merkle_root;
+    airdrop.min_stage = args.min_stage;
+    airdrop.num_leaves = args.num_leaves;
+    airdrop.claimed_count = 0;
+    airdrop.claimed_amount = 0;
+    airdrop.swept = false;
+    airdrop.bump = ctx.bumps.airdrop;
+    airdrop.version = Airdrop::VERSION;
+    airdrop.claimed_bitmap = vec![0; Airdrop::bitmap_len(args.num_leaves)];
+
+    emit!(AirdropCreated {
+        airdrop: airdrop.key(),
+        name: airdrop.name.clone(),
+        mint: airdrop.mint,
+        merkle_root: airdrop.merkle_root,
+        min_stage: airdrop.min_stage,
+        num_leaves: airdrop.num_leaves,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Airdrop Created: {} ({} leaves)", airdrop.name, airdrop.num_leaves);
+    Ok(())
+}
+
+/// Move whatever is left in the vault to `destination` and close the airdrop to claims
+pub fn sweep_handler(ctx: Context<SweepAirdrop>) -> Result<()> {
+    let amount = ctx.accounts.vault.amount;
+    let airdrop = &ctx.accounts.airdrop;
+    let signer_seeds: &[&[u8]] = &[b"airdrop", airdrop.name.as_bytes(), &[airdrop.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.airdrop.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.swept = true;
+
+    emit!(AirdropSwept {
+        airdrop: airdrop.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        claimed_count: airdrop.claimed_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Airdrop '{}' swept: {} unclaimed tokens ({} of {} leaves claimed)",
+        airdrop.name,
+        amount,
+        airdrop.claimed_count,
+        airdrop.num_leaves
+    );
+    Ok(())
+}
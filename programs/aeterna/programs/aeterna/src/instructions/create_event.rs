@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::event::Event;
+use crate::events::EventCreated;
 
 #[derive(Accounts)]
 #[instruction(name: String)]
@@ -25,6 +26,14 @@ pub fn handler(ctx: Context<CreateEvent>, name: String) -> Result<()> {
     event.name = name;
     event.active = true;
     event.bump = ctx.bumps.event;
+    event.version = Event::VERSION;
+
+    emit!(EventCreated {
+        event: event.key(),
+        authority: event.authority,
+        name: event.name.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Event Created: {}", event.name);
     Ok(())
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::event::Event;
+use crate::state::event_leaderboard::EventLeaderboard;
+use crate::events::LeaderboardCreated;
+
+#[derive(Accounts)]
+pub struct CreateLeaderboard<'info> {
+    /// The Event organizer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ crate::AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EventLeaderboard::SIZE,
+        seeds = [b"leaderboard", event.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, EventLeaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.event = ctx.accounts.event.key();
+    leaderboard.entries = Vec::new();
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.version = EventLeaderboard::VERSION;
+
+    emit!(LeaderboardCreated {
+        leaderboard: leaderboard.key(),
+        event: leaderboard.event,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Leaderboard Created for Event: {}", ctx.accounts.event.name);
+    Ok(())
+}
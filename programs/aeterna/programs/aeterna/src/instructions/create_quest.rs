@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::event::Event;
 use crate::state::quest::Quest;
+use crate::state::soul_stats::SoulStats;
+use crate::events::QuestCreated;
 
 #[derive(Accounts)]
 #[instruction(name: String, xp_reward: u64)]
@@ -27,12 +29,33 @@ pub struct CreateQuest<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateQuest>, name: String, xp_reward: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<CreateQuest>,
+    name: String,
+    xp_reward: u64,
+    min_wealth_tier: u8,
+) -> Result<()> {
+    require!(
+        min_wealth_tier <= SoulStats::WEALTH_DIAMOND,
+        crate::AeternaError::InvalidWealthTiers
+    );
+
     let quest = &mut ctx.accounts.quest;
     quest.event = ctx.accounts.event.key();
     quest.name = name;
     quest.xp_reward = xp_reward;
+    quest.min_wealth_tier = min_wealth_tier;
     quest.bump = ctx.bumps.quest;
+    quest.version = Quest::VERSION;
+
+    emit!(QuestCreated {
+        quest: quest.key(),
+        event: quest.event,
+        name: quest.name.clone(),
+        xp_reward: quest.xp_reward,
+        min_wealth_tier: quest.min_wealth_tier,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Quest Created: {} ({} XP)", quest.name, quest.xp_reward);
     Ok(())
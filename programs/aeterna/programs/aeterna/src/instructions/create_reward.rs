@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::event::Event;
+use crate::state::reward::Reward;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::{RewardCreated, RewardUpdated};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+#[instruction(args: RewardArgs)]
+pub struct CreateReward<'info> {
+    /// The event organizer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Reward::SIZE,
+        seeds = [b"reward", event.key().as_ref(), args.name.as_bytes()],
+        bump
+    )]
+    pub reward: Account<'info, Reward>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateReward<'info> {
+    /// The event organizer
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"reward", event.key().as_ref(), reward.name.as_bytes()],
+        bump = reward.bump,
+    )]
+    pub reward: Account<'info, Reward>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RewardArgs {
+    pub name: String,
+    pub cost: u64,
+    /// None = unlimited
+    pub stock: Option<u32>,
+    /// Minimum evolution stage (0 = open to all)
+    pub min_stage: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateRewardArgs {
+    pub cost: u64,
+    /// Restock or cap the remaining stock (None = unlimited)
+    pub stock: Option<u32>,
+    /// false retires the reward until it is reactivated
+    pub active: bool,
+}
+
+pub fn handler(ctx: Context<CreateReward>, args: RewardArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidReward
+    );
+    require!(args.cost > 0, AeternaError::InvalidReward);
+    require!(
+        args.min_stage <= aeterna_rules::stage::ASCENDED,
+        AeternaError::InvalidStage
+    );
+
+    let reward = &mut ctx.accounts.reward;
+    reward.event = ctx.accounts.event.key();
+    reward.name = args.name;
+    reward.cost = args.cost;
+    reward.stock = args.stock;
+    reward.min_stage = args.min_stage;
+    reward.active = true;
+    reward.redeemed = 0;
+    reward.bump = ctx.bumps.reward;
+    reward.version = Reward::VERSION;
+
+    emit!(RewardCreated {
+        reward: reward.key(),
+        event: reward.event,
+        name: reward.name.clone(),
+        cost: reward.cost,
+        stock: reward.stock,
+        min_stage: reward.min_stage,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Reward Created: {} ({} points)", reward.name, reward.cost);
+    Ok(())
+}
+
+pub fn update_handler(ctx: Context<UpdateReward>, args: UpdateRewardArgs) -> Result<()> {
+    require!(args.cost > 0, AeternaError::InvalidReward);
+
+    let reward = &mut ctx.accounts.reward;
+    reward.cost = args.cost;
+    reward.stock = args.stock;
+    reward.active = args.active;
+
+    emit!(RewardUpdated {
+        reward: reward.key(),
+        event: reward.event,
+        cost: reward.cost,
+        stock: reward.stock,
+        active: reward.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Reward Updated: {} ({} points, active: {})", reward.name, reward.cost, reward.active);
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use mpl_core::{
     ID as CORE_PROGRAM_ID,
-    instructions::{UpdatePluginV1Cpi, UpdatePluginV1CpiAccounts, UpdatePluginV1InstructionArgs},
-    types::{Attribute, Plugin, Attributes},
+    types::Attribute,
 };
 use crate::state::soul_stats::SoulStats;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::attributes;
+use crate::events::SoulEvolved;
 
 #[derive(Accounts)]
 pub struct EvolveSoul<'info> {
@@ -30,6 +32,10 @@ pub struct EvolveSoul<'info> {
     )]
     pub soul_stats: Account<'info, SoulStats>,
 
+    /// Protocol config — source of the Ascension wealth requirement
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
     /// The Metaplex Core Program
     /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
     #[account(address = CORE_PROGRAM_ID)]
@@ -54,29 +60,17 @@ pub struct EvolveArgs {
 pub fn handler(ctx: Context<EvolveSoul>, args: EvolveArgs) -> Result<()> {
     let soul_stats = &mut ctx.accounts.soul_stats;
 
-    // ── A5: Prevent stage downgrade ───────────────────────────────────────────
-    // An Ascended soul cannot be regressed to Active. Stage must always increase.
-    require!(
-        args.new_stage > soul_stats.current_stage,
-        crate::AeternaError::InvalidStage
-    );
-
-    // ── Gate evolution behind real on-chain XP thresholds ────────────────────
-    match args.new_stage {
-        1 => {
-            require!(
-                soul_stats.xp >= SoulStats::THRESHOLD_ACTIVE,
-                crate::AeternaError::NotEnoughXp
-            );
-        }
-        2 => {
-            require!(
-                soul_stats.xp >= SoulStats::THRESHOLD_ASCENDED,
-                crate::AeternaError::NotEnoughXp
-            );
-        }
-        _ => return Err(crate::AeternaError::InvalidStage.into()),
-    }
+    // ── A5 + XP gate + evaluation.md wealth gate (shared rules) ──────────────
+    // Stage must always increase, the target stage's XP threshold must be met,
+    // and Ascension may require a minimum wealth tier.
+    aeterna_rules::stage::check_evolution(
+        soul_stats.current_stage,
+        args.new_stage,
+        soul_stats.xp,
+        soul_stats.wealth_tier,
+        ctx.accounts.config.ascend_min_wealth_tier,
+    )
+    .map_err(crate::AeternaError::from)?;
 
     msg!(
         "Evolving Soul: {} | {} → {} | XP: {}",
@@ -87,42 +81,43 @@ pub fn handler(ctx: Context<EvolveSoul>, args: EvolveArgs) -> Result<()> {
     );
 
     // ── Update the on-chain stage record ─────────────────────────────────────
+    let previous_stage = soul_stats.current_stage;
     soul_stats.current_stage = args.new_stage;
 
-    // Build attributes including the new stage
-    let mut mpl_attributes: Vec<Attribute> = args.attributes.iter().map(|t| Attribute {
+    // ── Enforce the protocol attribute schema ────────────────────────────────
+    // Callers may only set cosmetic keys; stage/xp/status are owned by the program.
+    let custom_attributes: Vec<Attribute> = args.attributes.iter().map(|t| Attribute {
         key: t.key.clone(),
         value: t.value.clone(),
     }).collect();
+    attributes::validate_custom(&custom_attributes)?;
 
-    // Ensure stage, xp, and status are always written
-    mpl_attributes.push(Attribute { key: "stage".to_string(), value: args.new_stage.to_string() });
-    mpl_attributes.push(Attribute { key: "xp".to_string(), value: soul_stats.xp.to_string() });
-    mpl_attributes.push(Attribute {
-        key: "status".to_string(),
-        value: match args.new_stage {
-            1 => "Active".to_string(),
-            2 => "Ascended".to_string(),
-            _ => "Dormant".to_string(),
-        },
-    });
+    // Merge into the existing list so level/title and earlier traits are preserved
+    let mut mpl_attributes = attributes::load(&ctx.accounts.asset);
+    for attr in custom_attributes {
+        attributes::upsert(&mut mpl_attributes, &attr.key, attr.value);
+    }
 
-    UpdatePluginV1Cpi::new(
+    // Ensure stage, status, xp and the rest of the reserved keys are always written
+    attributes::upsert_soul(&mut mpl_attributes, soul_stats, &ctx.accounts.config);
+
+    attributes::write(
         &ctx.accounts.mpl_core_program,
-        UpdatePluginV1CpiAccounts {
-            asset: &ctx.accounts.asset,
-            collection: None,
-            authority: Some(&ctx.accounts.authority),
-            payer: &ctx.accounts.payer,
-            system_program: &ctx.accounts.system_program,
-            log_wrapper: None,
-        },
-        UpdatePluginV1InstructionArgs {
-            plugin: Plugin::Attributes(Attributes {
-                attribute_list: mpl_attributes,
-            }),
-        }
-    ).invoke()?;
+        &ctx.accounts.asset,
+        &ctx.accounts.authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        mpl_attributes,
+    )?;
+
+    emit!(SoulEvolved {
+        asset: ctx.accounts.asset.key(),
+        previous_stage,
+        new_stage: args.new_stage,
+        xp: soul_stats.xp,
+        new_uri: args.new_uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Soul Evolved to Stage {}.", args.new_stage);
     Ok(())
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::event::Event;
+use crate::state::reward::{Redemption, Reward};
+use crate::events::RedemptionFulfilled;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct FulfillRedemption<'info> {
+    /// The Event Authority (bar / merch staff scanner) — must match event.authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        seeds = [b"reward", event.key().as_ref(), reward.name.as_bytes()],
+        bump = reward.bump,
+        constraint = reward.event == event.key()
+    )]
+    pub reward: Account<'info, Reward>,
+
+    #[account(
+        mut,
+        seeds = [b"redemption", reward.key().as_ref(), &redemption.serial.to_le_bytes()],
+        bump = redemption.bump,
+        constraint = redemption.reward == reward.key()
+    )]
+    pub redemption: Account<'info, Redemption>,
+}
+
+pub fn handler(ctx: Context<FulfillRedemption>) -> Result<()> {
+    let redemption = &mut ctx.accounts.redemption;
+    require!(redemption.fulfilled_at == 0, AeternaError::AlreadyFulfilled);
+
+    let now = Clock::get()?.unix_timestamp;
+    redemption.fulfilled_at = now;
+
+    emit!(RedemptionFulfilled {
+        reward: redemption.reward,
+        redemption: redemption.key(),
+        asset: redemption.asset,
+        timestamp: now,
+    });
+
+    msg!("Redemption #{} of '{}' fulfilled", redemption.serial, ctx.accounts.reward.name);
+    Ok(())
+}
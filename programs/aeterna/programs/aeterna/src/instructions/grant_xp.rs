@@ -1,16 +1,30 @@
 use anchor_lang::prelude::*;
 use solana_program::pubkey;
+use mpl_core::ID as CORE_PROGRAM_ID;
 use crate::state::soul_stats::SoulStats;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::event_leaderboard::EventLeaderboard;
+use crate::state::event_participation::EventParticipation;
+use crate::state::merchant::Merchant;
+use crate::state::boost_window::BoostWindow;
+use crate::state::grant_receipt::GrantReceipt;
+use crate::state::xp_table::XpTable;
+use crate::attributes;
+use aeterna_rules::xp;
+use crate::events::{LevelUp, WealthTierChanged, XpBoosted, XpGranted};
 
 /// Hardcoded backend authority key using the `pubkey!` macro.
 /// This is zero-cost (compile-time constant), unlike `.to_string()` comparison.
 /// To rotate: update this constant and redeploy. Long-term: store in a config PDA.
-const BACKEND_AUTHORITY: Pubkey = pubkey!("AjdHrwHUVTu57Br3AZpEstDFdqE6Knh2LG6EtfTaAwnJ");
+pub const BACKEND_AUTHORITY: Pubkey = pubkey!("AjdHrwHUVTu57Br3AZpEstDFdqE6Knh2LG6EtfTaAwnJ");
 
 #[derive(Accounts)]
+#[instruction(args: GrantXpArgs)]
 pub struct GrantXp<'info> {
-    /// Must be the designated backend authority keypair
+    /// Must be the designated backend authority keypair.
+    /// Writes the asset's attributes on level-up only where it is also the asset's update authority.
     #[account(
+        mut,
         constraint = authority.key() == BACKEND_AUTHORITY @ crate::AeternaError::Unauthorized
     )]
     pub authority: Signer<'info>,
@@ -23,30 +37,131 @@ pub struct GrantXp<'info> {
     )]
     pub soul_stats: Account<'info, SoulStats>,
 
+    /// Protocol config — source of the level ladder
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// XP and volume weight per action type
+    #[account(seeds = [b"xp_table"], bump = xp_table.bump)]
+    pub xp_table: Account<'info, XpTable>,
+
+    /// The Metaplex Core Asset this SoulStats belongs to
+    /// CHECK: Bound to soul_stats.asset; written only via Core CPI
+    #[account(mut, address = soul_stats.asset)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    /// Supplied for event-scoped grants: the XP also counts towards this event's participation
+    #[account(
+        mut,
+        seeds = [b"participation", participation.event.as_ref(), soul_stats.asset.as_ref()],
+        bump = participation.bump,
+    )]
+    pub participation: Option<Account<'info, EventParticipation>>,
+
+    /// The leaderboard address for `participation`'s event — required with `participation`,
+    /// updated in place once created
+    /// CHECK: Address checked against the participation's event in handler
+    #[account(mut)]
+    pub leaderboard: Option<UncheckedAccount<'info>>,
+
+    /// Supplied when the grant is for a spend at a registered merchant: XP for
+    /// `add_trading_volume` is computed from the merchant's rate on top of the action's table XP
+    #[account(
+        seeds = [b"merchant", merchant.event.as_ref(), merchant.name.as_bytes()],
+        bump = merchant.bump,
+        constraint = merchant.active @ crate::AeternaError::MerchantInactive
+    )]
+    pub merchant: Option<Account<'info, Merchant>>,
+
+    /// Optional boost window — multiplies the merchant-rate XP while live and in scope.
+    /// Ignored without `merchant`.
+    #[account(
+        seeds = [b"boost", boost.event.as_ref(), boost.name.as_bytes()],
+        bump = boost.bump,
+    )]
+    pub boost: Option<Account<'info, BoostWindow>>,
+
+    /// ── DEDUP: fails to init if this receipt id was already credited ──
+    /// Seeds: ["grant_receipt", args.receipt_id]
+    #[account(
+        init,
+        payer = authority,
+        space = GrantReceipt::SIZE,
+        seeds = [b"grant_receipt", args.receipt_id.as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, GrantReceipt>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct GrantXpArgs {
-    pub xp_amount: u64,
+    /// Idempotency key, e.g. the hash of the USDC transfer signature being credited
+    pub receipt_id: [u8; 32],
+    /// `XpTable` action type id
+    pub action: u8,
+    /// Number of occurrences of `action` being credited (1 to the entry's `max_count`)
+    pub count: u32,
+    /// Spend in volume units (`aeterna_rules::wealth::VOLUME_DECIMALS`), weighted by the
+    /// action's `volume_weight_bps` before it counts towards trading volume and merchant XP
     pub add_trading_volume: Option<u64>,
     pub quests_completed: Option<u32>,
 }
 
 pub fn handler(ctx: Context<GrantXp>, args: GrantXpArgs) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let entry = ctx
+        .accounts
+        .xp_table
+        .entry(args.action)
+        .ok_or(crate::AeternaError::UnknownAction)?;
+    require!(entry.allows_count(args.count), crate::AeternaError::InvalidActionCount);
+    let action_xp = entry.xp_for(args.count);
+    let added_volume = args.add_trading_volume.map(|vol| entry.weighted_volume(vol));
+
+    let merchant_xp = match ctx.accounts.merchant.as_ref() {
+        Some(merchant) => merchant.xp_for_volume(added_volume.unwrap_or(0), &ctx.accounts.config),
+        None => 0,
+    };
+
+    // Only spend-driven XP is boosted; the action's table XP is not
+    let boost = match (ctx.accounts.merchant.as_ref(), ctx.accounts.boost.as_ref()) {
+        (Some(merchant), Some(boost)) if merchant_xp > 0 => boost
+            .merchant_multiplier(&merchant.key(), &merchant.event, now)
+            .map(|bps| (boost.key(), bps)),
+        _ => None,
+    };
+    let boosted_merchant_xp = match boost {
+        Some((_, multiplier_bps)) => xp::boost(merchant_xp, multiplier_bps),
+        None => merchant_xp,
+    };
+    let xp_amount = action_xp.saturating_add(boosted_merchant_xp);
+
     let soul_stats = &mut ctx.accounts.soul_stats;
+    soul_stats.record_grant(xp_amount, &ctx.accounts.config, now)?;
+    let xp_before = soul_stats.xp;
+    let trading_volume_before = soul_stats.trading_volume;
+    let quests_completed_before = soul_stats.quests_completed;
 
     msg!(
-        "GrantXP: Asset {} | +{} XP | Total after: {}",
+        "GrantXP: Asset {} | {} x{} | +{} XP | Total after: {}",
         soul_stats.asset,
-        args.xp_amount,
-        soul_stats.xp.saturating_add(args.xp_amount)
+        entry.name,
+        args.count,
+        xp_amount,
+        soul_stats.xp.saturating_add(xp_amount)
     );
 
-    soul_stats.xp = soul_stats.xp.saturating_add(args.xp_amount);
+    soul_stats.earn(xp_amount);
     
     // Add DeFi tracking if passed
-    if let Some(vol) = args.add_trading_volume {
+    if let Some(vol) = added_volume {
         soul_stats.trading_volume = soul_stats.trading_volume.saturating_add(vol);
         msg!("Trading Volume update: +{} -> {}", vol, soul_stats.trading_volume);
     }
@@ -56,5 +171,101 @@ pub fn handler(ctx: Context<GrantXp>, args: GrantXpArgs) -> Result<()> {
         soul_stats.quests_completed = soul_stats.quests_completed.saturating_add(quests);
     }
 
+    if let Some(participation) = ctx.accounts.participation.as_mut() {
+        participation.last_seen = now;
+        participation.add_xp(xp_amount);
+    }
+
+    match (ctx.accounts.participation.as_ref(), ctx.accounts.leaderboard.as_ref()) {
+        (Some(participation), Some(leaderboard)) => {
+            let (expected, _) =
+                Pubkey::find_program_address(&[b"leaderboard", participation.event.as_ref()], ctx.program_id);
+            require_keys_eq!(leaderboard.key(), expected, crate::AeternaError::LeaderboardRequired);
+            EventLeaderboard::record_if_created(leaderboard, soul_stats.asset, participation.event_xp)?;
+        }
+        (Some(_), None) => return err!(crate::AeternaError::LeaderboardRequired),
+        (None, Some(_)) => return err!(crate::AeternaError::ParticipationRequired),
+        (None, None) => {}
+    }
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.receipt_id = args.receipt_id;
+    receipt.asset = soul_stats.asset;
+    receipt.xp_amount = xp_amount;
+    receipt.granted_at = now;
+    receipt.action = args.action;
+    receipt.count = args.count;
+    receipt.bump = ctx.bumps.receipt;
+    receipt.version = GrantReceipt::VERSION;
+
+    emit!(XpGranted {
+        asset: soul_stats.asset,
+        receipt_id: args.receipt_id,
+        action: args.action,
+        count: args.count,
+        xp_amount,
+        xp_before,
+        xp_after: soul_stats.xp,
+        trading_volume_before,
+        trading_volume_after: soul_stats.trading_volume,
+        quests_completed_before,
+        quests_completed_after: soul_stats.quests_completed,
+        timestamp: now,
+    });
+
+    if let Some((boost, multiplier_bps)) = boost {
+        emit!(XpBoosted {
+            boost,
+            asset: soul_stats.asset,
+            base_xp: merchant_xp,
+            boosted_xp: boosted_merchant_xp,
+            multiplier_bps,
+            timestamp: now,
+        });
+    }
+
+    // ── Recompute level and wealth tier; only touch the Core asset when stale ──
+    // Comparing against the asset rather than the previous level also syncs levels and
+    // tiers reached through `pulse_pay` / `claim_action_xp`, which cannot write the asset.
+    let config = &ctx.accounts.config;
+    let level_change = soul_stats.refresh_level(config);
+    let wealth_change = soul_stats.refresh_wealth_tier(config);
+
+    // Passes minted by an organizer are theirs to update; those catch up on the
+    // soul's next `complete_quest` / `evolve_soul` instead of reverting the grant
+    if attributes::is_update_authority(&ctx.accounts.asset, &BACKEND_AUTHORITY)? {
+        let mut mpl_attributes = attributes::load(&ctx.accounts.asset);
+        if attributes::is_tier_stale(&mpl_attributes, soul_stats, config) {
+            attributes::upsert_soul(&mut mpl_attributes, soul_stats, config);
+            attributes::write(
+                &ctx.accounts.mpl_core_program,
+                &ctx.accounts.asset,
+                &ctx.accounts.authority,
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+                mpl_attributes,
+            )?;
+        }
+    }
+
+    if let Some(previous_level) = level_change {
+        emit!(LevelUp {
+            asset: soul_stats.asset,
+            previous_level,
+            new_level: soul_stats.level,
+            title: config.title_for(soul_stats.level).to_string(),
+            xp: soul_stats.xp,
+        });
+    }
+
+    if let Some(previous_tier) = wealth_change {
+        emit!(WealthTierChanged {
+            asset: soul_stats.asset,
+            previous_tier,
+            new_tier: soul_stats.wealth_tier,
+            trading_volume: soul_stats.trading_volume,
+        });
+    }
+
     Ok(())
 }
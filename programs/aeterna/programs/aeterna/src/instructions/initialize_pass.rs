@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::state::soul_stats::SoulStats;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::event_participation::EventParticipation;
+use crate::events::PassMinted;
 use mpl_core::{
     ID as CORE_PROGRAM_ID,
     instructions::{CreateV1Cpi, CreateV1InstructionArgs, CreateV1CpiAccounts},
-    types::{Attribute, Plugin, Attributes, PluginAuthorityPair, DataState},
+    types::{Plugin, Attributes, PluginAuthorityPair, DataState},
 };
 
 #[derive(Accounts)]
@@ -37,7 +40,7 @@ pub struct InitializePass<'info> {
     #[account(
         seeds = [b"event", event.name.as_bytes()],
         bump = event.bump,
-        constraint = event.active == true
+        constraint = event.active
     )]
     pub event: Account<'info, crate::state::event::Event>,
 
@@ -51,6 +54,20 @@ pub struct InitializePass<'info> {
     )]
     pub soul_stats: Account<'info, SoulStats>,
 
+    /// The pass's participation record at its minting event (first interaction)
+    #[account(
+        init,
+        payer = authority,
+        space = EventParticipation::SIZE,
+        seeds = [b"participation", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub participation: Account<'info, EventParticipation>,
+
+    /// Protocol config — source of the initial level title
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -73,13 +90,20 @@ pub fn handler(ctx: Context<InitializePass>, args: InitArgs) -> Result<()> {
     soul_stats.quests_completed = 0;
     soul_stats.trading_volume = 0;
     soul_stats.bump = ctx.bumps.soul_stats;
+    soul_stats.version = SoulStats::VERSION;
+    soul_stats.level = ctx.accounts.config.level_for(0);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.participation.visit(
+        ctx.accounts.event.key(),
+        ctx.accounts.asset.key(),
+        ctx.bumps.participation,
+        now,
+    );
 
     // ── 2. Build initial attributes ──────────────────────────────────────────
-    let initial_attributes = vec![
-        Attribute { key: "status".to_string(), value: "Dormant".to_string() },
-        Attribute { key: "stage".to_string(), value: "0".to_string() },
-        Attribute { key: "xp".to_string(), value: "0".to_string() },
-    ];
+    let mut initial_attributes = Vec::new();
+    crate::attributes::upsert_soul(&mut initial_attributes, soul_stats, &ctx.accounts.config);
 
     let plugins = vec![
         PluginAuthorityPair {
@@ -114,7 +138,7 @@ pub fn handler(ctx: Context<InitializePass>, args: InitArgs) -> Result<()> {
         },
         CreateV1InstructionArgs {
             name: "AETERNA Pass".to_string(),
-            uri: args.uri,
+            uri: args.uri.clone(),
             plugins: Some(plugins),
             data_state: DataState::AccountState,
         }
@@ -125,5 +149,14 @@ pub fn handler(ctx: Context<InitializePass>, args: InitArgs) -> Result<()> {
         ctx.accounts.signer.key()
     );
 
+    emit!(PassMinted {
+        asset: ctx.accounts.asset.key(),
+        owner: ctx.accounts.signer.key(),
+        event: ctx.accounts.event.key(),
+        soul_stats: ctx.accounts.soul_stats.key(),
+        uri: args.uri,
+        timestamp: now,
+    });
+
     Ok(())
 }
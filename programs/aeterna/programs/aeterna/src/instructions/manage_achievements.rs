@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as CORE_PROGRAM_ID,
+    instructions::{CreateCollectionV1Cpi, CreateCollectionV1CpiAccounts, CreateCollectionV1InstructionArgs},
+};
+use crate::state::achievement::{Achievement, Criterion, StatField};
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::{AchievementCreated, BadgeCollectionInitialized};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct InitializeBadgeCollection<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Fresh keypair for the Core collection
+    #[account(mut)]
+    pub collection: Signer<'info>,
+
+    /// Program PDA that is the collection's update authority, so `claim_achievement` can mint
+    /// CHECK: PDA derived from seeds; holds no data
+    #[account(seeds = [b"badge_authority"], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: AchievementArgs)]
+pub struct CreateAchievement<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Achievement::SIZE,
+        seeds = [b"achievement", args.name.as_bytes()],
+        bump
+    )]
+    pub achievement: Account<'info, Achievement>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BadgeCollectionArgs {
+    pub name: String,
+    pub uri: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AchievementArgs {
+    pub name: String,
+    pub uri: String,
+    pub criteria: Vec<Criterion>,
+    pub event: Option<Pubkey>,
+    pub max_supply: u32,
+}
+
+pub fn initialize_collection_handler(
+    ctx: Context<InitializeBadgeCollection>,
+    args: BadgeCollectionArgs,
+) -> Result<()> {
+    CreateCollectionV1Cpi::new(
+        &ctx.accounts.mpl_core_program,
+        CreateCollectionV1CpiAccounts {
+            collection: &ctx.accounts.collection,
+            update_authority: Some(&ctx.accounts.badge_authority),
+            payer: &ctx.accounts.authority,
+            system_program: &ctx.accounts.system_program,
+        },
+        CreateCollectionV1InstructionArgs {
+            name: args.name,
+            uri: args.uri,
+            plugins: None,
+        }
+    ).invoke()?;
+
+    ctx.accounts.config.badge_collection = ctx.accounts.collection.key();
+
+    emit!(BadgeCollectionInitialized {
+        collection: ctx.accounts.collection.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Badge Collection Created: {}", ctx.accounts.collection.key());
+    Ok(())
+}
+
+pub fn create_handler(ctx: Context<CreateAchievement>, args: AchievementArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidAchievement
+    );
+    require!(args.uri.len() <= Achievement::MAX_URI_LEN, AeternaError::InvalidAchievement);
+    require!(
+        !args.criteria.is_empty() && args.criteria.len() <= Achievement::MAX_CRITERIA,
+        AeternaError::InvalidAchievement
+    );
+    // Event-scoped criteria only make sense when the achievement names an event
+    require!(
+        args.event.is_some()
+            || args.criteria.iter().all(|c| {
+                !matches!(c.field, StatField::EventXp | StatField::EventQuestsCompleted)
+            }),
+        AeternaError::InvalidAchievement
+    );
+
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.name = args.name;
+    achievement.uri = args.uri;
+    achievement.criteria = args.criteria;
+    achievement.event = args.event;
+    achievement.max_supply = args.max_supply;
+    achievement.claimed = 0;
+    achievement.bump = ctx.bumps.achievement;
+    achievement.version = Achievement::VERSION;
+
+    emit!(AchievementCreated {
+        achievement: achievement.key(),
+        name: achievement.name.clone(),
+        event: achievement.event,
+        max_supply: achievement.max_supply,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Achievement Created: {}", achievement.name);
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::action_type::ActionType;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::xp_table::XpTable;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::ActionTypeUpdated;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+#[instruction(args: ActionTypeArgs)]
+pub struct CreateActionType<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// The action's XP comes from this table, so its entry must exist
+    #[account(seeds = [b"xp_table"], bump = xp_table.bump)]
+    pub xp_table: Account<'info, XpTable>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ActionType::SIZE,
+        seeds = [b"action_type", args.name.as_bytes()],
+        bump
+    )]
+    pub action: Account<'info, ActionType>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateActionType<'info> {
+    /// Protocol admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// The action's XP comes from this table, so its entry must exist
+    #[account(seeds = [b"xp_table"], bump = xp_table.bump)]
+    pub xp_table: Account<'info, XpTable>,
+
+    #[account(
+        mut,
+        seeds = [b"action_type", action.name.as_bytes()],
+        bump = action.bump,
+    )]
+    pub action: Account<'info, ActionType>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ActionTypeArgs {
+    pub name: String,
+    /// `XpTable` action id that prices each claim
+    pub xp_action: u8,
+    pub programs: Vec<Pubkey>,
+    /// Seconds between claims by the same soul (must be non-zero)
+    pub cooldown: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateActionTypeArgs {
+    pub xp_action: u8,
+    pub programs: Vec<Pubkey>,
+    pub cooldown: u32,
+    pub active: bool,
+}
+
+pub fn create_handler(ctx: Context<CreateActionType>, args: ActionTypeArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidActionType
+    );
+    validate_action(&ctx.accounts.xp_table, args.xp_action, &args.programs, args.cooldown)?;
+
+    let action = &mut ctx.accounts.action;
+    action.name = args.name;
+    action.xp_action = args.xp_action;
+    action.programs = args.programs;
+    action.cooldown = args.cooldown;
+    action.active = true;
+    action.bump = ctx.bumps.action;
+    action.version = ActionType::VERSION;
+
+    emit!(ActionTypeUpdated {
+        action: action.key(),
+        name: action.name.clone(),
+        xp_action: action.xp_action,
+        programs: action.programs.clone(),
+        cooldown: action.cooldown,
+        active: action.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Action Type Created: {} (XP table action {})", action.name, action.xp_action);
+    Ok(())
+}
+
+pub fn update_handler(ctx: Context<UpdateActionType>, args: UpdateActionTypeArgs) -> Result<()> {
+    validate_action(&ctx.accounts.xp_table, args.xp_action, &args.programs, args.cooldown)?;
+
+    let action = &mut ctx.accounts.action;
+    action.xp_action = args.xp_action;
+    action.programs = args.programs;
+    action.cooldown = args.cooldown;
+    action.active = args.active;
+
+    emit!(ActionTypeUpdated {
+        action: action.key(),
+        name: action.name.clone(),
+        xp_action: action.xp_action,
+        programs: action.programs.clone(),
+        cooldown: action.cooldown,
+        active: action.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Action Type Updated: {} (active: {})", action.name, action.active);
+    Ok(())
+}
+
+/// At least one program, and never this program — otherwise `claim_action_xp` would match itself.
+/// The cooldown must be non-zero so a soul cannot claim the same action back to back.
+fn validate_action(xp_table: &XpTable, xp_action: u8, programs: &[Pubkey], cooldown: u32) -> Result<()> {
+    require!(xp_table.entry(xp_action).is_some(), AeternaError::UnknownAction);
+    require!(
+        !programs.is_empty() && programs.len() <= ActionType::MAX_PROGRAMS,
+        AeternaError::InvalidActionType
+    );
+    require!(!programs.contains(&crate::ID), AeternaError::InvalidActionType);
+    require!(cooldown > 0, AeternaError::InvalidActionType);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as CORE_PROGRAM_ID,
+    instructions::{CreateCollectionV1Cpi, CreateCollectionV1CpiAccounts, CreateCollectionV1InstructionArgs},
+};
+use crate::state::event::{Event, ReentryPolicy};
+use crate::events::{AttendanceEnabled, ReentryPolicyUpdated};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct EnableAttendance<'info> {
+    /// The event organizer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    /// Fresh keypair for the event's attendance collection
+    #[account(mut)]
+    pub collection: Signer<'info>,
+
+    /// Program PDA that is the collection's update authority, so `record_attendance` can mint
+    /// CHECK: PDA derived from seeds; holds no data
+    #[account(seeds = [b"attendance_authority"], bump)]
+    pub attendance_authority: UncheckedAccount<'info>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AttendanceArgs {
+    /// Collection name, e.g. "Solana Breakpoint 2025 — I Was There"
+    pub name: String,
+    /// Event-branded metadata URI shared by every attendance proof
+    pub uri: String,
+}
+
+pub fn enable_handler(ctx: Context<EnableAttendance>, args: AttendanceArgs) -> Result<()> {
+    require!(!ctx.accounts.event.attendance_enabled(), AeternaError::AttendanceAlreadyEnabled);
+
+    CreateCollectionV1Cpi::new(
+        &ctx.accounts.mpl_core_program,
+        CreateCollectionV1CpiAccounts {
+            collection: &ctx.accounts.collection,
+            update_authority: Some(&ctx.accounts.attendance_authority),
+            payer: &ctx.accounts.authority,
+            system_program: &ctx.accounts.system_program,
+        },
+        CreateCollectionV1InstructionArgs {
+            name: args.name,
+            uri: args.uri,
+            plugins: None,
+        }
+    ).invoke()?;
+
+    let event = &mut ctx.accounts.event;
+    event.attendance_collection = ctx.accounts.collection.key();
+
+    emit!(AttendanceEnabled {
+        event: event.key(),
+        collection: event.attendance_collection,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Attendance proofs enabled for '{}': {}", event.name, event.attendance_collection);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetReentryPolicy<'info> {
+    /// The event organizer
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+}
+
+pub fn set_reentry_policy_handler(
+    ctx: Context<SetReentryPolicy>,
+    policy: ReentryPolicy,
+    cooldown: u32,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.reentry_policy = policy;
+    event.reentry_cooldown = cooldown;
+
+    emit!(ReentryPolicyUpdated {
+        event: event.key(),
+        policy,
+        cooldown,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Re-entry policy for '{}': {:?} ({}s cooldown)", event.name, policy, cooldown);
+    Ok(())
+}
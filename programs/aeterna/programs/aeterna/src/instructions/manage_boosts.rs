@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::boost_window::{BoostScope, BoostWindow};
+use crate::state::event::Event;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::{BoostWindowClosed, BoostWindowCreated};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+#[instruction(args: BoostWindowArgs)]
+pub struct CreateBoostWindow<'info> {
+    /// The event organizer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BoostWindow::SIZE,
+        seeds = [b"boost", event.key().as_ref(), args.name.as_bytes()],
+        bump
+    )]
+    pub boost: Account<'info, BoostWindow>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBoostWindow<'info> {
+    /// The event organizer — receives the rent back
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"boost", event.key().as_ref(), boost.name.as_bytes()],
+        bump = boost.bump,
+    )]
+    pub boost: Account<'info, BoostWindow>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BoostWindowArgs {
+    pub name: String,
+    pub start: i64,
+    pub end: i64,
+    /// 10_000 = 1x, up to `BoostWindow::MAX_MULTIPLIER_BPS`
+    pub multiplier_bps: u16,
+    pub scope: BoostScope,
+}
+
+pub fn create_handler(ctx: Context<CreateBoostWindow>, args: BoostWindowArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidBoostWindow
+    );
+    require!(args.start < args.end, AeternaError::InvalidBoostWindow);
+    require!(
+        (BoostWindow::MIN_MULTIPLIER_BPS..=BoostWindow::MAX_MULTIPLIER_BPS).contains(&args.multiplier_bps),
+        AeternaError::InvalidBoostWindow
+    );
+    if let BoostScope::Quests(quests) = &args.scope {
+        require!(
+            !quests.is_empty() && quests.len() <= BoostWindow::MAX_QUESTS,
+            AeternaError::InvalidBoostWindow
+        );
+    }
+
+    let boost = &mut ctx.accounts.boost;
+    boost.event = ctx.accounts.event.key();
+    boost.name = args.name;
+    boost.start = args.start;
+    boost.end = args.end;
+    boost.multiplier_bps = args.multiplier_bps;
+    boost.scope = args.scope;
+    boost.bump = ctx.bumps.boost;
+    boost.version = BoostWindow::VERSION;
+
+    emit!(BoostWindowCreated {
+        boost: boost.key(),
+        event: boost.event,
+        name: boost.name.clone(),
+        start: boost.start,
+        end: boost.end,
+        multiplier_bps: boost.multiplier_bps,
+        scope: boost.scope.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Boost Window Created: {} ({} bps, {} -> {})",
+        boost.name,
+        boost.multiplier_bps,
+        boost.start,
+        boost.end
+    );
+    Ok(())
+}
+
+pub fn close_handler(ctx: Context<CloseBoostWindow>) -> Result<()> {
+    emit!(BoostWindowClosed {
+        boost: ctx.accounts.boost.key(),
+        event: ctx.accounts.boost.event,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Boost Window Closed: {}", ctx.accounts.boost.name);
+    Ok(())
+}
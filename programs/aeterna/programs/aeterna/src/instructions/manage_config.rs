@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+use crate::state::protocol_config::{LevelTier, ProtocolConfig};
+use crate::state::xp_table::{XpTable, XpTableEntry};
+use crate::state::soul_stats::SoulStats;
+use crate::instructions::grant_xp::BACKEND_AUTHORITY;
+use crate::events::{LevelTableUpdated, PaymentConfigUpdated, WealthTiersUpdated, XpCapsUpdated, XpTableUpdated};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Only the backend authority may bootstrap the protocol config
+    #[account(
+        mut,
+        constraint = authority.key() == BACKEND_AUTHORITY @ AeternaError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeXpTable<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = XpTable::SIZE,
+        seeds = [b"xp_table"],
+        bump
+    )]
+    pub xp_table: Account<'info, XpTable>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateXpTable<'info> {
+    /// Protocol admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"xp_table"], bump = xp_table.bump)]
+    pub xp_table: Account<'info, XpTable>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WealthTierArgs {
+    pub gold_min_volume: u64,
+    pub diamond_min_volume: u64,
+    pub ascend_min_wealth_tier: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct XpCapArgs {
+    /// Max XP per credit, from any source (0 = unlimited)
+    pub max_grant_xp: u64,
+    /// Max XP per soul per fixed 24h window (0 = unlimited)
+    pub daily_xp_cap: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PaymentConfigArgs {
+    /// The stablecoin `pulse_pay` accepts (default = Pulse payments disabled)
+    pub stable_mint: Pubkey,
+    /// Highest XP per whole token a merchant may award (0 = unlimited)
+    pub max_xp_per_unit: u64,
+}
+
+pub fn initialize_handler(ctx: Context<InitializeConfig>, levels: Vec<LevelTier>) -> Result<()> {
+    validate_levels(&levels)?;
+
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.levels = levels;
+    config.bump = ctx.bumps.config;
+    config.version = ProtocolConfig::VERSION;
+
+    emit!(LevelTableUpdated {
+        levels: config.levels.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Protocol Config Initialized: {} levels", config.levels.len());
+    Ok(())
+}
+
+pub fn update_levels_handler(ctx: Context<UpdateConfig>, levels: Vec<LevelTier>) -> Result<()> {
+    validate_levels(&levels)?;
+    ctx.accounts.config.levels = levels;
+
+    emit!(LevelTableUpdated {
+        levels: ctx.accounts.config.levels.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Level Table Updated: {} levels", ctx.accounts.config.levels.len());
+    Ok(())
+}
+
+pub fn update_wealth_tiers_handler(ctx: Context<UpdateConfig>, args: WealthTierArgs) -> Result<()> {
+    require!(
+        args.ascend_min_wealth_tier <= SoulStats::WEALTH_DIAMOND,
+        AeternaError::InvalidWealthTiers
+    );
+    require!(
+        aeterna_rules::wealth::is_valid_thresholds(args.gold_min_volume, args.diamond_min_volume),
+        AeternaError::InvalidWealthTiers
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.gold_min_volume = args.gold_min_volume;
+    config.diamond_min_volume = args.diamond_min_volume;
+    config.ascend_min_wealth_tier = args.ascend_min_wealth_tier;
+
+    emit!(WealthTiersUpdated {
+        gold_min_volume: config.gold_min_volume,
+        diamond_min_volume: config.diamond_min_volume,
+        ascend_min_wealth_tier: config.ascend_min_wealth_tier,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Wealth Tiers Updated: Gold >= {} | Diamond >= {} | Ascend requires tier {}",
+        config.gold_min_volume,
+        config.diamond_min_volume,
+        config.ascend_min_wealth_tier
+    );
+    Ok(())
+}
+
+pub fn update_xp_caps_handler(ctx: Context<UpdateConfig>, args: XpCapArgs) -> Result<()> {
+    require!(
+        args.max_grant_xp == 0 || args.daily_xp_cap == 0 || args.max_grant_xp <= args.daily_xp_cap,
+        AeternaError::InvalidXpCaps
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.max_grant_xp = args.max_grant_xp;
+    config.daily_xp_cap = args.daily_xp_cap;
+
+    emit!(XpCapsUpdated {
+        max_grant_xp: config.max_grant_xp,
+        daily_xp_cap: config.daily_xp_cap,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "XP Caps Updated: {} per grant | {} per soul per day",
+        config.max_grant_xp,
+        config.daily_xp_cap
+    );
+    Ok(())
+}
+
+pub fn update_payment_config_handler(ctx: Context<UpdateConfig>, args: PaymentConfigArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.stable_mint = args.stable_mint;
+    config.max_xp_per_unit = args.max_xp_per_unit;
+
+    emit!(PaymentConfigUpdated {
+        stable_mint: config.stable_mint,
+        max_xp_per_unit: config.max_xp_per_unit,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Payment Config Updated: stablecoin {} | max {} XP per unit",
+        config.stable_mint,
+        config.max_xp_per_unit
+    );
+    Ok(())
+}
+
+pub fn initialize_xp_table_handler(ctx: Context<InitializeXpTable>, entries: Vec<XpTableEntry>) -> Result<()> {
+    validate_xp_table(&entries)?;
+
+    let xp_table = &mut ctx.accounts.xp_table;
+    xp_table.entries = entries;
+    xp_table.bump = ctx.bumps.xp_table;
+    xp_table.version = XpTable::VERSION;
+
+    emit!(XpTableUpdated {
+        entries: xp_table.entries.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("XP Table Initialized: {} actions", xp_table.entries.len());
+    Ok(())
+}
+
+pub fn update_xp_table_handler(ctx: Context<UpdateXpTable>, entries: Vec<XpTableEntry>) -> Result<()> {
+    validate_xp_table(&entries)?;
+    ctx.accounts.xp_table.entries = entries;
+
+    emit!(XpTableUpdated {
+        entries: ctx.accounts.xp_table.entries.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("XP Table Updated: {} actions", ctx.accounts.xp_table.entries.len());
+    Ok(())
+}
+
+/// Action ids must be unique, otherwise `XpTable::entry` would silently pick the first.
+/// A zero `max_count` would make the action ungrantable.
+fn validate_xp_table(entries: &[XpTableEntry]) -> Result<()> {
+    require!(entries.len() <= XpTable::MAX_ENTRIES, AeternaError::InvalidXpTable);
+    for (i, entry) in entries.iter().enumerate() {
+        require!(
+            !entry.name.is_empty() && entry.name.len() <= XpTable::MAX_NAME_LEN,
+            AeternaError::InvalidXpTable
+        );
+        require!(
+            entry.volume_weight_bps <= XpTable::MAX_VOLUME_WEIGHT_BPS,
+            AeternaError::InvalidXpTable
+        );
+        require!(entry.max_count > 0, AeternaError::InvalidXpTable);
+        require!(
+            entries[..i].iter().all(|other| other.action != entry.action),
+            AeternaError::InvalidXpTable
+        );
+    }
+    Ok(())
+}
+
+/// Level 1 must start at 0 XP and thresholds must be strictly ascending,
+/// otherwise `ProtocolConfig::level_for` would be ambiguous.
+fn validate_levels(levels: &[LevelTier]) -> Result<()> {
+    require!(
+        !levels.is_empty() && levels.len() <= ProtocolConfig::MAX_LEVELS,
+        AeternaError::InvalidLevelTable
+    );
+    let thresholds: Vec<u64> = levels.iter().map(|tier| tier.min_xp).collect();
+    require!(
+        aeterna_rules::level::is_valid_ladder(&thresholds),
+        AeternaError::InvalidLevelTable
+    );
+    require!(
+        levels.iter().all(|tier| tier.title.len() <= ProtocolConfig::MAX_TITLE_LEN),
+        AeternaError::InvalidLevelTable
+    );
+    Ok(())
+}
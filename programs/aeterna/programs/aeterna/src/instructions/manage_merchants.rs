@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::event::Event;
+use crate::state::merchant::Merchant;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::events::{MerchantUpdated, TreasuryVaultInitialized, TreasuryWithdrawn};
+use crate::AeternaError;
+
+#[derive(Accounts)]
+#[instruction(args: CreateMerchantArgs)]
+pub struct CreateMerchant<'info> {
+    /// The event organizer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    /// Protocol config — source of the merchant XP rate cap
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Merchant::SIZE,
+        seeds = [b"merchant", event.key().as_ref(), args.name.as_bytes()],
+        bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// The merchant's payout token account
+    pub payout: Account<'info, TokenAccount>,
+
+    #[account(address = payout.mint)]
+    pub mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMerchant<'info> {
+    /// The event organizer
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    /// Protocol config — source of the merchant XP rate cap
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"merchant", event.key().as_ref(), merchant.name.as_bytes()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+}
+
+#[derive(Accounts)]
+pub struct SetMerchantFee<'info> {
+    /// Protocol admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"merchant", merchant.event.as_ref(), merchant.name.as_bytes()],
+        bump = merchant.bump,
+    )]
+    pub merchant: Account<'info, Merchant>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasuryVault<'info> {
+    /// Protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Collects protocol fees in `mint`, withdrawable only by the config PDA
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = config,
+        seeds = [b"treasury", mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Protocol admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AeternaError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"treasury", treasury_vault.mint.as_ref()], bump)]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = treasury_vault.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMerchantArgs {
+    pub name: String,
+    /// XP per whole token spent (None = protocol default), capped by `ProtocolConfig::max_xp_per_unit`
+    pub xp_per_unit: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMerchantArgs {
+    pub xp_per_unit: u64,
+    pub active: bool,
+}
+
+pub fn create_handler(ctx: Context<CreateMerchant>, args: CreateMerchantArgs) -> Result<()> {
+    require!(
+        !args.name.is_empty() && args.name.len() <= MAX_SEED_NAME_LEN,
+        AeternaError::InvalidMerchant
+    );
+    let xp_per_unit = args.xp_per_unit.unwrap_or(aeterna_rules::xp::DEFAULT_XP_PER_UNIT);
+    require!(ctx.accounts.config.allows_xp_rate(xp_per_unit), AeternaError::XpRateTooHigh);
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.event = ctx.accounts.event.key();
+    merchant.name = args.name;
+    merchant.payout = ctx.accounts.payout.key();
+    merchant.mint = ctx.accounts.mint.key();
+    merchant.decimals = ctx.accounts.mint.decimals;
+    merchant.xp_per_unit = xp_per_unit;
+    merchant.fee_bps = 0;
+    merchant.active = true;
+    merchant.bump = ctx.bumps.merchant;
+    merchant.version = Merchant::VERSION;
+
+    emit_updated(merchant)?;
+    msg!("Merchant Created: {} ({} XP per unit)", merchant.name, merchant.xp_per_unit);
+    Ok(())
+}
+
+pub fn update_handler(ctx: Context<UpdateMerchant>, args: UpdateMerchantArgs) -> Result<()> {
+    require!(ctx.accounts.config.allows_xp_rate(args.xp_per_unit), AeternaError::XpRateTooHigh);
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.xp_per_unit = args.xp_per_unit;
+    merchant.active = args.active;
+
+    emit_updated(merchant)?;
+    msg!("Merchant Updated: {} (active: {})", merchant.name, merchant.active);
+    Ok(())
+}
+
+pub fn set_fee_handler(ctx: Context<SetMerchantFee>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= Merchant::MAX_FEE_BPS, AeternaError::InvalidMerchant);
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.fee_bps = fee_bps;
+
+    emit_updated(merchant)?;
+    msg!("Merchant Fee Set: {} -> {} bps", merchant.name, merchant.fee_bps);
+    Ok(())
+}
+
+pub fn initialize_treasury_handler(ctx: Context<InitializeTreasuryVault>) -> Result<()> {
+    emit!(TreasuryVaultInitialized {
+        vault: ctx.accounts.treasury_vault.key(),
+        mint: ctx.accounts.mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Treasury Vault for {}: {}",
+        ctx.accounts.mint.key(),
+        ctx.accounts.treasury_vault.key()
+    );
+    Ok(())
+}
+
+pub fn withdraw_treasury_handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let signer_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        vault: ctx.accounts.treasury_vault.key(),
+        mint: ctx.accounts.treasury_vault.mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Treasury Withdraw: {} to {}", amount, ctx.accounts.destination.key());
+    Ok(())
+}
+
+fn emit_updated(merchant: &Account<Merchant>) -> Result<()> {
+    emit!(MerchantUpdated {
+        merchant: merchant.key(),
+        event: merchant.event,
+        name: merchant.name.clone(),
+        payout: merchant.payout,
+        xp_per_unit: merchant.xp_per_unit,
+        fee_bps: merchant.fee_bps,
+        active: merchant.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::AeternaError;
+use crate::events::{PulseLimitsUpdated, PulseLinked, PulseUnlinked};
 
 #[derive(Accounts)]
 pub struct ManagePulse<'info> {
@@ -51,10 +52,144 @@ pub fn handler(ctx: Context<ManagePulse>, args: PulseArgs) -> Result<()> {
     require!(asset_account.owner == ctx.accounts.owner.key(), AeternaError::Unauthorized);
 
     let pulse_link = &mut ctx.accounts.pulse_link;
+    // init_if_needed: a zeroed wallet means this is the first link for the asset.
+    // Re-registering rotates the wallet and rebinds the link to the current owner.
+    let previous_wallet = (pulse_link.pulse_wallet != Pubkey::default())
+        .then_some(pulse_link.pulse_wallet);
+    // A new owner must not inherit the seller's spending limits or allowlist. Balances
+    // never move with the link: vaults are per owner (see `pulse_vault`).
+    if pulse_link.owner != ctx.accounts.owner.key() {
+        pulse_link.per_tx_cap = 0;
+        pulse_link.daily_cap = 0;
+        pulse_link.window_start = 0;
+        pulse_link.window_spent = 0;
+        pulse_link.allowlist.clear();
+    }
     pulse_link.asset = ctx.accounts.asset.key();
     pulse_link.pulse_wallet = args.pulse_wallet;
+    pulse_link.owner = ctx.accounts.owner.key();
     pulse_link.bump = ctx.bumps.pulse_link;
+    pulse_link.version = PulseLink::VERSION;
+
+    emit!(PulseLinked {
+        asset: pulse_link.asset,
+        owner: ctx.accounts.owner.key(),
+        previous_wallet,
+        pulse_wallet: pulse_link.pulse_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Pulse Link Established.");
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct UnregisterPulse<'info> {
+    /// The current asset owner, or the owner who created the link
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Validated by BaseAssetV1 manual deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [b"pulse", asset.key().as_ref()],
+        bump = pulse_link.bump,
+        constraint = pulse_link.asset == asset.key()
+    )]
+    pub pulse_link: Account<'info, PulseLink>,
+
+    /// Gets the link's rent back — the owner who made it, not whoever closes it
+    /// CHECK: Bound to `PulseLink::rent_recipient`; only receives lamports
+    #[account(mut, address = pulse_link.rent_recipient(&owner.key()))]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn unregister_handler(ctx: Context<UnregisterPulse>) -> Result<()> {
+    let signer = ctx.accounts.owner.key();
+    let pulse_link = &ctx.accounts.pulse_link;
+
+    // A seller can still revoke their own link after the pass has moved on
+    let asset_owner = {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?.owner
+    };
+    require!(
+        signer == asset_owner || signer == pulse_link.owner,
+        AeternaError::Unauthorized
+    );
+
+    emit!(PulseUnlinked {
+        asset: pulse_link.asset,
+        owner: signer,
+        pulse_wallet: pulse_link.pulse_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Pulse Link Closed for Asset: {}", pulse_link.asset);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPulseLimits<'info> {
+    /// The asset owner — limits bind the Pulse wallet, so only the owner may change them
+    pub owner: Signer<'info>,
+
+    /// CHECK: Validated by BaseAssetV1 manual deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pulse", asset.key().as_ref()],
+        bump = pulse_link.bump,
+        constraint = pulse_link.asset == asset.key()
+    )]
+    pub pulse_link: Account<'info, PulseLink>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PulseLimitsArgs {
+    pub per_tx_cap: u64,
+    pub daily_cap: u64,
+    /// Wallets (merchants) whose token accounts may receive spends
+    pub allowlist: Vec<Pubkey>,
+}
+
+pub fn set_limits_handler(ctx: Context<SetPulseLimits>, args: PulseLimitsArgs) -> Result<()> {
+    let asset_owner = {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?.owner
+    };
+    require_keys_eq!(asset_owner, ctx.accounts.owner.key(), AeternaError::Unauthorized);
+
+    let pulse_link = &mut ctx.accounts.pulse_link;
+    require!(pulse_link.is_valid_for(&asset_owner), AeternaError::PulseLinkInvalid);
+    require!(
+        args.per_tx_cap <= args.daily_cap && args.allowlist.len() <= PulseLink::MAX_ALLOWLIST,
+        AeternaError::InvalidSpendLimits
+    );
+
+    pulse_link.per_tx_cap = args.per_tx_cap;
+    pulse_link.daily_cap = args.daily_cap;
+    pulse_link.allowlist = args.allowlist;
+
+    emit!(PulseLimitsUpdated {
+        asset: pulse_link.asset,
+        owner: asset_owner,
+        per_tx_cap: pulse_link.per_tx_cap,
+        daily_cap: pulse_link.daily_cap,
+        allowlist: pulse_link.allowlist.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Pulse limits for Asset {}: {} per tx, {} per day, {} allowed recipients",
+        pulse_link.asset,
+        pulse_link.per_tx_cap,
+        pulse_link.daily_cap,
+        pulse_link.allowlist.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::events::AccountMigrated;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays for any additional rent required by the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Any AETERNA program account in a legacy layout.
+    /// Migration only grows the account and stamps the version, so it is permissionless.
+    /// Layouts with appended fields (v1 `PulseLink`, v1 `ProtocolConfig`) are too short to
+    /// deserialize as `Account<T>`, so they are unreadable by every other instruction until
+    /// migrated here.
+    /// CHECK: Owner and discriminator are validated in the handler
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts that carry a layout `version` and can be upgraded in place
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const SIZE: usize;
+    const VERSION: u8;
+    fn version_mut(&mut self) -> &mut u8;
+}
+
+macro_rules! impl_versioned {
+    ($($ty:ty),*) => {
+        $(impl Versioned for $ty {
+            const SIZE: usize = <$ty>::SIZE;
+            const VERSION: u8 = <$ty>::VERSION;
+            fn version_mut(&mut self) -> &mut u8 {
+                &mut self.version
+            }
+        })*
+    };
+}
+
+impl_versioned!(SoulStats, Event, Quest, PulseLink, CompletionRecord, ProtocolConfig);
+
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let target = ctx.accounts.target.to_account_info();
+    require_keys_eq!(*target.owner, crate::ID, AeternaError::InvalidAccountOwner);
+
+    let discriminator: [u8; 8] = {
+        let data = target.try_borrow_data()?;
+        require!(data.len() >= 8, AeternaError::UnknownAccountType);
+        data[..8].try_into().unwrap()
+    };
+
+    match discriminator {
+        d if d == SoulStats::DISCRIMINATOR => migrate::<SoulStats>(&ctx, &target),
+        d if d == Event::DISCRIMINATOR => migrate::<Event>(&ctx, &target),
+        d if d == Quest::DISCRIMINATOR => migrate::<Quest>(&ctx, &target),
+        d if d == PulseLink::DISCRIMINATOR => migrate::<PulseLink>(&ctx, &target),
+        d if d == CompletionRecord::DISCRIMINATOR => migrate::<CompletionRecord>(&ctx, &target),
+        d if d == ProtocolConfig::DISCRIMINATOR => migrate::<ProtocolConfig>(&ctx, &target),
+        _ => err!(AeternaError::UnknownAccountType),
+    }
+}
+
+fn migrate<'info, T: Versioned>(
+    ctx: &Context<MigrateAccount<'info>>,
+    target: &AccountInfo<'info>,
+) -> Result<()> {
+    // ── 1. Grow to the current layout, topping up rent from the payer ────────
+    // Realloc zero-fills the new bytes, so appended fields read as their zero default.
+    if target.data_len() < T::SIZE {
+        let required = Rent::get()?.minimum_balance(T::SIZE);
+        let shortfall = required.saturating_sub(target.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: target.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        target.realloc(T::SIZE, true)?;
+    }
+
+    // ── 2. Stamp the current version ─────────────────────────────────────────
+    let mut account = T::try_deserialize(&mut &target.try_borrow_data()?[..])?;
+    let previous = *account.version_mut();
+    require!(previous < T::VERSION, AeternaError::AlreadyMigrated);
+    *account.version_mut() = T::VERSION;
+    account.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: target.key(),
+        previous_version: previous,
+        new_version: T::VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Migrated {} from v{} to v{}", target.key(), previous, T::VERSION);
+    Ok(())
+}
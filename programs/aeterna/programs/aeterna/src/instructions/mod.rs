@@ -1,3 +1,7 @@
+// Every module exposes `handler`-style functions; lib.rs calls them by module path
+// and only needs the globs for the `Accounts` structs Anchor generates.
+#![allow(ambiguous_glob_reexports)]
+
 pub mod evolve;
 pub mod initialize_pass;
 pub mod manage_pulse;
@@ -5,6 +9,27 @@ pub mod create_event;
 pub mod create_quest;
 pub mod complete_quest;
 pub mod grant_xp;
+pub mod migrate_account;
+pub mod manage_config;
+pub mod create_leaderboard;
+pub mod manage_achievements;
+pub mod claim_achievement;
+pub mod manage_attendance;
+pub mod record_attendance;
+pub mod create_reward;
+pub mod redeem_reward;
+pub mod fulfill_redemption;
+pub mod create_airdrop;
+pub mod claim_airdrop;
+pub mod check_in;
+pub mod check_out;
+pub mod pulse_vault;
+pub mod pulse_pay;
+pub mod manage_merchants;
+pub mod manage_boosts;
+pub mod close_grant_receipt;
+pub mod manage_actions;
+pub mod claim_action_xp;
 
 pub use evolve::*;
 pub use initialize_pass::*;
@@ -13,3 +38,24 @@ pub use create_event::*;
 pub use create_quest::*;
 pub use complete_quest::*;
 pub use grant_xp::*;
+pub use migrate_account::*;
+pub use manage_config::*;
+pub use create_leaderboard::*;
+pub use manage_achievements::*;
+pub use claim_achievement::*;
+pub use manage_attendance::*;
+pub use record_attendance::*;
+pub use create_reward::*;
+pub use redeem_reward::*;
+pub use fulfill_redemption::*;
+pub use create_airdrop::*;
+pub use claim_airdrop::*;
+pub use check_in::*;
+pub use check_out::*;
+pub use pulse_vault::*;
+pub use pulse_pay::*;
+pub use manage_merchants::*;
+pub use manage_boosts::*;
+pub use close_grant_receipt::*;
+pub use manage_actions::*;
+pub use claim_action_xp::*;
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::instructions::pulse_vault::{asset_owner, transfer_from_vault};
+use crate::state::boost_window::BoostWindow;
+use crate::state::merchant::Merchant;
+use crate::state::protocol_config::ProtocolConfig;
+use crate::state::pulse_link::PulseLink;
+use crate::state::soul_stats::SoulStats;
+use crate::events::{LevelUp, PulsePayment, WealthTierChanged, XpBoosted};
+use crate::AeternaError;
+
+/// A Pulse tap: pays a registered merchant from the Pulse vault, takes the protocol fee and
+/// credits the linked soul in the same instruction, so spend and XP can never drift apart.
+#[derive(Accounts)]
+pub struct PulsePay<'info> {
+    /// The linked Pulse wallet (Turnkey session key)
+    pub pulse_wallet: Signer<'info>,
+
+    /// The pass the Pulse wallet is linked to
+    /// CHECK: Deserialized in handler to check the link is still valid
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pulse", asset.key().as_ref()],
+        bump = pulse_link.bump,
+        has_one = pulse_wallet @ AeternaError::Unauthorized,
+        constraint = pulse_link.asset == asset.key()
+    )]
+    pub pulse_link: Account<'info, PulseLink>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    /// Protocol config — source of the level ladder, wealth tiers, XP caps and approved stablecoin
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// The merchant being paid — sets the XP rate and protocol fee
+    #[account(
+        seeds = [b"merchant", merchant.event.as_ref(), merchant.name.as_bytes()],
+        bump = merchant.bump,
+        constraint = merchant.active @ AeternaError::MerchantInactive
+    )]
+    pub merchant: Account<'info, Merchant>,
+
+    /// The token being spent — the one the merchant accepts, and the protocol's stablecoin
+    #[account(
+        address = merchant.mint,
+        constraint = mint.key() == config.stable_mint @ AeternaError::MintNotApproved
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Signs for the link owner's vault
+    /// CHECK: Data-less PDA, only used as the vault's token authority
+    #[account(seeds = [b"pulse_vault", asset.key().as_ref(), pulse_link.owner.as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The link owner's vault for `mint`
+    #[account(
+        mut,
+        token::authority = vault_authority,
+        address = get_associated_token_address(&vault_authority.key(), &mint.key())
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The merchant's payout account — its owner must be on the link's allowlist
+    #[account(mut, address = merchant.payout)]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// Protocol fee vault for this mint
+    #[account(mut, seeds = [b"treasury", mint.key().as_ref()], bump)]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    /// Optional boost window at the merchant's event — multiplies the XP while live and in scope
+    #[account(
+        seeds = [b"boost", merchant.event.as_ref(), boost.name.as_bytes()],
+        bump = boost.bump,
+    )]
+    pub boost: Option<Account<'info, BoostWindow>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<PulsePay>, amount: u64) -> Result<()> {
+    let owner = asset_owner(&ctx.accounts.asset)?;
+    require!(ctx.accounts.pulse_link.is_valid_for(&owner), AeternaError::PulseLinkInvalid);
+
+    // The full amount counts against the owner's caps, fee included
+    let now = Clock::get()?.unix_timestamp;
+    let payee = ctx.accounts.destination.owner;
+    ctx.accounts.pulse_link.record_spend(&payee, amount, now)?;
+
+    let merchant = &ctx.accounts.merchant;
+    let asset = ctx.accounts.asset.key();
+    let fee = merchant.fee_for(amount);
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.vault_authority,
+        &asset,
+        &owner,
+        ctx.bumps.vault_authority,
+        amount - fee,
+    )?;
+    if fee > 0 {
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.vault_authority,
+            &asset,
+            &owner,
+            ctx.bumps.vault_authority,
+            fee,
+        )?;
+    }
+
+    // ── Credit the soul — Dormant souls still build volume but earn no XP ────
+    let soul_stats = &mut ctx.accounts.soul_stats;
+    let xp_before = soul_stats.xp;
+    let trading_volume_before = soul_stats.trading_volume;
+    let base_xp = if aeterna_rules::stage::can_earn_xp(soul_stats.current_stage) {
+        merchant.xp_for(amount, &ctx.accounts.config)
+    } else {
+        0
+    };
+    let boost = ctx
+        .accounts
+        .boost
+        .as_ref()
+        .filter(|_| base_xp > 0)
+        .and_then(|boost| {
+            boost
+                .merchant_multiplier(&merchant.key(), &merchant.event, now)
+                .map(|bps| (boost.key(), bps))
+        });
+    let xp_awarded = match boost {
+        Some((_, multiplier_bps)) => aeterna_rules::xp::boost(base_xp, multiplier_bps),
+        None => base_xp,
+    };
+    soul_stats.record_grant(xp_awarded, &ctx.accounts.config, now)?;
+    soul_stats.earn(xp_awarded);
+    let volume = aeterna_rules::wealth::normalize_volume(amount, ctx.accounts.mint.decimals);
+    soul_stats.trading_volume = soul_stats.trading_volume.saturating_add(volume);
+
+    // Core attributes are written by the asset's update authority, not the Pulse wallet,
+    // so they catch up on the soul's next `complete_quest` / `evolve_soul`, or on its next
+    // `grant_xp` when the backend is the update authority.
+    let config = &ctx.accounts.config;
+    let level_change = soul_stats.refresh_level(config);
+    let wealth_change = soul_stats.refresh_wealth_tier(config);
+
+    emit!(PulsePayment {
+        asset: soul_stats.asset,
+        pulse_wallet: ctx.accounts.pulse_wallet.key(),
+        merchant: merchant.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        fee,
+        xp_awarded,
+        xp_before,
+        xp_after: soul_stats.xp,
+        trading_volume_before,
+        trading_volume_after: soul_stats.trading_volume,
+        timestamp: now,
+    });
+
+    if let Some((boost, multiplier_bps)) = boost {
+        emit!(XpBoosted {
+            boost,
+            asset: soul_stats.asset,
+            base_xp,
+            boosted_xp: xp_awarded,
+            multiplier_bps,
+            timestamp: now,
+        });
+    }
+
+    if let Some(previous_level) = level_change {
+        emit!(LevelUp {
+            asset: soul_stats.asset,
+            previous_level,
+            new_level: soul_stats.level,
+            title: config.title_for(soul_stats.level).to_string(),
+            xp: soul_stats.xp,
+        });
+    }
+
+    if let Some(previous_tier) = wealth_change {
+        emit!(WealthTierChanged {
+            asset: soul_stats.asset,
+            previous_tier,
+            new_tier: soul_stats.wealth_tier,
+            trading_volume: soul_stats.trading_volume,
+        });
+    }
+
+    msg!("Pulse Pay: {} to '{}' (fee {}) | +{} XP", amount, merchant.name, fee, xp_awarded);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::pulse_link::PulseLink;
+use crate::events::{PulseSpent, PulseWithdrawn};
+use crate::AeternaError;
+
+/// Pulse funds live in the associated token accounts of a per-owner vault authority
+/// ["pulse_vault", asset, owner], so a balance stays with the owner who funded it when the
+/// pass is sold: a buyer who re-registers spends from a fresh, empty vault of their own.
+/// Funds only leave through `pulse_spend` / `pulse_pay` (capped, allowlisted) or
+/// `pulse_withdraw` (the funding owner only).
+#[derive(Accounts)]
+pub struct PulseSpend<'info> {
+    /// The linked Pulse wallet (Turnkey session key)
+    pub pulse_wallet: Signer<'info>,
+
+    /// The pass the Pulse wallet is linked to
+    /// CHECK: Deserialized in handler to check the link is still valid
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pulse", asset.key().as_ref()],
+        bump = pulse_link.bump,
+        has_one = pulse_wallet @ AeternaError::Unauthorized,
+        constraint = pulse_link.asset == asset.key()
+    )]
+    pub pulse_link: Account<'info, PulseLink>,
+
+    /// Signs for the link owner's vault
+    /// CHECK: Data-less PDA, only used as the vault's token authority
+    #[account(seeds = [b"pulse_vault", asset.key().as_ref(), pulse_link.owner.as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The link owner's vault for this mint
+    #[account(
+        mut,
+        token::authority = vault_authority,
+        address = get_associated_token_address(&vault_authority.key(), &vault.mint)
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Recipient token account — its owner must be on the link's allowlist
+    #[account(mut, token::mint = vault.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Works whether or not the owner still holds the pass or its link — the vault is derived
+/// from the signer, so nobody else can reach it.
+#[derive(Accounts)]
+pub struct PulseWithdraw<'info> {
+    /// The owner who funded the vault
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only used to derive the vault authority
+    pub asset: UncheckedAccount<'info>,
+
+    /// Signs for the owner's vault
+    /// CHECK: Data-less PDA, only used as the vault's token authority
+    #[account(seeds = [b"pulse_vault", asset.key().as_ref(), owner.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::authority = vault_authority,
+        address = get_associated_token_address(&vault_authority.key(), &vault.mint)
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Owner of `asset` per Metaplex Core
+pub(crate) fn asset_owner(asset: &AccountInfo) -> Result<Pubkey> {
+    let asset_data = asset.try_borrow_data()?;
+    Ok(mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?.owner)
+}
+
+/// Transfer `amount` out of the Pulse vault of (`asset`, `owner`), signed by its vault authority
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transfer_from_vault<'info>(
+    token_program: &Program<'info, Token>,
+    vault: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    vault_authority: &UncheckedAccount<'info>,
+    asset: &Pubkey,
+    owner: &Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[u8]] = &[b"pulse_vault", asset.as_ref(), owner.as_ref(), &[bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: vault_authority.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )
+}
+
+pub fn spend_handler(ctx: Context<PulseSpend>, amount: u64) -> Result<()> {
+    // ── A link made by a previous owner of the pass is dead ──────────────────
+    let owner = asset_owner(&ctx.accounts.asset)?;
+    require!(ctx.accounts.pulse_link.is_valid_for(&owner), AeternaError::PulseLinkInvalid);
+
+    let now = Clock::get()?.unix_timestamp;
+    let recipient = ctx.accounts.destination.owner;
+    ctx.accounts.pulse_link.record_spend(&recipient, amount, now)?;
+
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.vault_authority,
+        &ctx.accounts.pulse_link.asset,
+        &owner,
+        ctx.bumps.vault_authority,
+        amount,
+    )?;
+
+    emit!(PulseSpent {
+        asset: ctx.accounts.pulse_link.asset,
+        pulse_wallet: ctx.accounts.pulse_wallet.key(),
+        destination: ctx.accounts.destination.key(),
+        mint: ctx.accounts.vault.mint,
+        amount,
+        timestamp: now,
+    });
+
+    msg!(
+        "Pulse spend: {} to {} ({} of {} used today)",
+        amount,
+        recipient,
+        ctx.accounts.pulse_link.window_spent,
+        ctx.accounts.pulse_link.daily_cap
+    );
+    Ok(())
+}
+
+pub fn withdraw_handler(ctx: Context<PulseWithdraw>, amount: u64) -> Result<()> {
+    // The vault is derived from the signer, so a seller can still recover funds after
+    // selling the pass and a buyer can never reach them.
+    let signer = ctx.accounts.owner.key();
+    let asset = ctx.accounts.asset.key();
+
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.vault_authority,
+        &asset,
+        &signer,
+        ctx.bumps.vault_authority,
+        amount,
+    )?;
+
+    emit!(PulseWithdrawn {
+        asset,
+        owner: signer,
+        destination: ctx.accounts.destination.key(),
+        mint: ctx.accounts.vault.mint,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Pulse withdraw: {} to {}", amount, ctx.accounts.destination.key());
+    Ok(())
+}
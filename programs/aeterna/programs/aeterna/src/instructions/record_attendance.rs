@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as CORE_PROGRAM_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    instructions::{CreateV1Cpi, CreateV1CpiAccounts, CreateV1InstructionArgs},
+    types::{DataState, PermanentFreezeDelegate, Plugin, PluginAuthorityPair},
+};
+use crate::state::attendance_record::AttendanceRecord;
+use crate::state::event::Event;
+use crate::state::event_participation::EventParticipation;
+use crate::events::AttendanceRecorded;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct RecordAttendance<'info> {
+    /// The Event Authority (gate scanner) — must match event.authority
+    pub authority: Signer<'info>,
+
+    /// Pays for the AttendanceRecord, participation and proof asset rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Event being attended — must be active with attendance proofs enabled
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.active,
+        constraint = event.authority == authority.key() @ AeternaError::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The pass being checked in
+    /// CHECK: Deserialized in handler to read the current owner
+    pub asset: UncheckedAccount<'info>,
+
+    /// The pass owner, who receives the proof
+    /// CHECK: Verified against the asset's owner in handler
+    pub owner: UncheckedAccount<'info>,
+
+    /// The soul's record at this event — created on its first interaction
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EventParticipation::SIZE,
+        seeds = [b"participation", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub participation: Account<'info, EventParticipation>,
+
+    /// ── DEDUP: at most one attendance proof per (event, asset) ──
+    #[account(
+        init,
+        payer = payer,
+        space = AttendanceRecord::SIZE,
+        seeds = [b"attendance", event.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub attendance: Account<'info, AttendanceRecord>,
+
+    /// Fresh keypair for the attendance proof asset
+    #[account(mut)]
+    pub proof: Signer<'info>,
+
+    /// CHECK: Must be the event's attendance collection; validated by Core during CPI
+    #[account(
+        mut,
+        address = event.attendance_collection @ AeternaError::AttendanceNotEnabled
+    )]
+    pub attendance_collection: UncheckedAccount<'info>,
+
+    /// CHECK: PDA that is every attendance collection's update authority; signs the mint
+    #[account(seeds = [b"attendance_authority"], bump)]
+    pub attendance_authority: UncheckedAccount<'info>,
+
+    /// The Metaplex Core Program
+    /// CHECK: Validated via address constraint against CORE_PROGRAM_ID
+    #[account(address = CORE_PROGRAM_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordAttendance>) -> Result<()> {
+    let event = &ctx.accounts.event;
+    require!(event.attendance_enabled(), AeternaError::AttendanceNotEnabled);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.participation.visit(
+        event.key(),
+        ctx.accounts.asset.key(),
+        ctx.bumps.participation,
+        now,
+    );
+
+    ProofMint {
+        event,
+        asset: &ctx.accounts.asset,
+        owner: &ctx.accounts.owner,
+        payer: &ctx.accounts.payer,
+        proof: &ctx.accounts.proof,
+        attendance_collection: &ctx.accounts.attendance_collection,
+        attendance_authority: &ctx.accounts.attendance_authority,
+        attendance_authority_bump: ctx.bumps.attendance_authority,
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        system_program: &ctx.accounts.system_program,
+    }
+    .mint(&mut ctx.accounts.attendance, ctx.bumps.attendance, now)
+}
+
+/// Accounts for minting an attendance proof, shared by `record_attendance` and a soul's
+/// first `check_in` at an event with proofs enabled
+pub(crate) struct ProofMint<'a, 'info> {
+    pub event: &'a Account<'info, Event>,
+    pub asset: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub proof: &'a AccountInfo<'info>,
+    pub attendance_collection: &'a AccountInfo<'info>,
+    pub attendance_authority: &'a AccountInfo<'info>,
+    pub attendance_authority_bump: u8,
+    pub mpl_core_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl ProofMint<'_, '_> {
+    /// Mint a frozen proof to the pass's current owner and fill in its `attendance` record
+    pub fn mint(&self, attendance: &mut AttendanceRecord, attendance_bump: u8, now: i64) -> Result<()> {
+        // ── The proof goes to whoever holds the pass right now ───────────────
+        {
+            let asset_data = self.asset.try_borrow_data()?;
+            let asset = BaseAssetV1::from_bytes(&asset_data)?;
+            require_keys_eq!(asset.owner, self.owner.key(), AeternaError::Unauthorized);
+        }
+
+        // Every proof shares the collection's event-branded metadata
+        let uri = {
+            let collection_data = self.attendance_collection.try_borrow_data()?;
+            BaseCollectionV1::from_bytes(&collection_data)?.uri
+        };
+
+        // ── Mint a frozen (non-transferable) proof into the event's collection ─
+        let signer_seeds: &[&[u8]] = &[b"attendance_authority", &[self.attendance_authority_bump]];
+        CreateV1Cpi::new(
+            self.mpl_core_program,
+            CreateV1CpiAccounts {
+                asset: self.proof,
+                collection: Some(self.attendance_collection),
+                authority: Some(self.attendance_authority),
+                payer: self.payer,
+                owner: Some(self.owner),
+                update_authority: None,
+                system_program: self.system_program,
+                log_wrapper: None,
+            },
+            CreateV1InstructionArgs {
+                name: self.event.name.clone(),
+                uri,
+                plugins: Some(vec![PluginAuthorityPair {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+                    authority: None,
+                }]),
+                data_state: DataState::AccountState,
+            }
+        ).invoke_signed(&[signer_seeds])?;
+
+        attendance.event = self.event.key();
+        attendance.asset = self.asset.key();
+        attendance.owner = self.owner.key();
+        attendance.proof = self.proof.key();
+        attendance.checked_in_at = now;
+        attendance.bump = attendance_bump;
+        attendance.version = AttendanceRecord::VERSION;
+
+        emit!(AttendanceRecorded {
+            event: attendance.event,
+            asset: attendance.asset,
+            owner: attendance.owner,
+            proof: attendance.proof,
+            timestamp: now,
+        });
+
+        msg!("Attendance recorded at '{}' for Asset: {}", self.event.name, attendance.asset);
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::event::Event;
+use crate::state::reward::{Redemption, Reward};
+use crate::state::soul_stats::SoulStats;
+use crate::events::RewardRedeemed;
+use crate::AeternaError;
+
+#[derive(Accounts)]
+pub struct RedeemReward<'info> {
+    /// The pass owner — spends the soul's points and pays for the receipt
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The AETERNA Pass whose points are spent
+    /// CHECK: Owner verified in handler via BaseAssetV1 deserialization
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stats", asset.key().as_ref()],
+        bump = soul_stats.bump,
+        constraint = soul_stats.asset == asset.key()
+    )]
+    pub soul_stats: Account<'info, SoulStats>,
+
+    /// The reward's event — must still be active
+    #[account(
+        seeds = [b"event", event.name.as_bytes()],
+        bump = event.bump,
+        constraint = event.active
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"reward", event.key().as_ref(), reward.name.as_bytes()],
+        bump = reward.bump,
+        constraint = reward.event == event.key()
+    )]
+    pub reward: Account<'info, Reward>,
+
+    /// Receipt numbered by the reward's redemption count
+    #[account(
+        init,
+        payer = owner,
+        space = Redemption::SIZE,
+        seeds = [b"redemption", reward.key().as_ref(), &reward.redeemed.to_le_bytes()],
+        bump
+    )]
+    pub redemption: Account<'info, Redemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RedeemReward>) -> Result<()> {
+    {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        let asset = mpl_core::accounts::BaseAssetV1::from_bytes(&asset_data)?;
+        require_keys_eq!(asset.owner, ctx.accounts.owner.key(), AeternaError::Unauthorized);
+    }
+
+    let soul_stats = &mut ctx.accounts.soul_stats;
+    let reward = &mut ctx.accounts.reward;
+
+    require!(reward.active, AeternaError::RewardInactive);
+    require!(reward.in_stock(), AeternaError::RewardOutOfStock);
+    require!(soul_stats.current_stage >= reward.min_stage, AeternaError::StageTooLow);
+    require!(soul_stats.points >= reward.cost, AeternaError::InsufficientPoints);
+
+    let points_before = soul_stats.points;
+    soul_stats.points -= reward.cost;
+
+    let serial = reward.redeemed;
+    reward.redeemed = reward.redeemed.saturating_add(1);
+    if let Some(stock) = reward.stock.as_mut() {
+        *stock -= 1;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let redemption = &mut ctx.accounts.redemption;
+    redemption.reward = reward.key();
+    redemption.asset = soul_stats.asset;
+    redemption.owner = ctx.accounts.owner.key();
+    redemption.cost = reward.cost;
+    redemption.serial = serial;
+    redemption.redeemed_at = now;
+    redemption.fulfilled_at = 0;
+    redemption.bump = ctx.bumps.redemption;
+    redemption.version = Redemption::VERSION;
+
+    emit!(RewardRedeemed {
+        reward: reward.key(),
+        redemption: redemption.key(),
+        asset: redemption.asset,
+        owner: redemption.owner,
+        serial,
+        cost: reward.cost,
+        points_before,
+        points_after: soul_stats.points,
+        timestamp: now,
+    });
+
+    msg!("Reward '{}' #{} redeemed by Asset: {}", reward.name, serial, redemption.asset);
+    Ok(())
+}
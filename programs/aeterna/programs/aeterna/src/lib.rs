@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+pub mod attributes;
+pub mod events;
+pub mod merkle;
 pub mod instructions;
 pub use instructions::*;
 pub mod state;
@@ -21,6 +24,66 @@ pub mod aeterna {
         instructions::manage_pulse::handler(ctx, args)
     }
 
+    /// Close a pass's Pulse link (current owner, or the owner who created it, who gets the rent back)
+    pub fn unregister_pulse_wallet(ctx: Context<UnregisterPulse>) -> Result<()> {
+        instructions::manage_pulse::unregister_handler(ctx)
+    }
+
+    /// Set the Pulse wallet's per-transaction cap, daily cap and recipient allowlist (owner only)
+    pub fn set_pulse_limits(ctx: Context<SetPulseLimits>, args: PulseLimitsArgs) -> Result<()> {
+        instructions::manage_pulse::set_limits_handler(ctx, args)
+    }
+
+    /// Spend from the Pulse vault, enforcing the owner's caps and allowlist
+    pub fn pulse_spend(ctx: Context<PulseSpend>, amount: u64) -> Result<()> {
+        instructions::pulse_vault::spend_handler(ctx, amount)
+    }
+
+    /// Withdraw from the caller's own Pulse vault for a pass to any account, linked or not
+    pub fn pulse_withdraw(ctx: Context<PulseWithdraw>, amount: u64) -> Result<()> {
+        instructions::pulse_vault::withdraw_handler(ctx, amount)
+    }
+
+    /// Pay a registered merchant from the Pulse vault and credit volume and XP in one step
+    pub fn pulse_pay(ctx: Context<PulsePay>, amount: u64) -> Result<()> {
+        instructions::pulse_pay::handler(ctx, amount)
+    }
+
+    /// Register a merchant at an event with its payout account and XP rate (organizer only)
+    pub fn create_merchant(ctx: Context<CreateMerchant>, args: CreateMerchantArgs) -> Result<()> {
+        instructions::manage_merchants::create_handler(ctx, args)
+    }
+
+    /// Change a merchant's XP rate or pause it (organizer only)
+    pub fn update_merchant(ctx: Context<UpdateMerchant>, args: UpdateMerchantArgs) -> Result<()> {
+        instructions::manage_merchants::update_handler(ctx, args)
+    }
+
+    /// Set the protocol fee taken from a merchant's payments (admin only)
+    pub fn set_merchant_fee(ctx: Context<SetMerchantFee>, fee_bps: u16) -> Result<()> {
+        instructions::manage_merchants::set_fee_handler(ctx, fee_bps)
+    }
+
+    /// Create the protocol fee vault for a mint (admin only)
+    pub fn initialize_treasury_vault(ctx: Context<InitializeTreasuryVault>) -> Result<()> {
+        instructions::manage_merchants::initialize_treasury_handler(ctx)
+    }
+
+    /// Move collected protocol fees out of a treasury vault (admin only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::manage_merchants::withdraw_treasury_handler(ctx, amount)
+    }
+
+    /// Open a timed XP multiplier window at an event (organizer only)
+    pub fn create_boost_window(ctx: Context<CreateBoostWindow>, args: BoostWindowArgs) -> Result<()> {
+        instructions::manage_boosts::create_handler(ctx, args)
+    }
+
+    /// Remove a boost window and reclaim its rent (organizer only)
+    pub fn close_boost_window(ctx: Context<CloseBoostWindow>) -> Result<()> {
+        instructions::manage_boosts::close_handler(ctx)
+    }
+
     /// Evolve a Soul to the next stage (gated by on-chain XP threshold)
     pub fn evolve_soul(ctx: Context<EvolveSoul>, args: EvolveArgs) -> Result<()> {
         instructions::evolve::handler(ctx, args)
@@ -32,8 +95,18 @@ pub mod aeterna {
     }
 
     /// Add a Quest to an existing Event
-    pub fn create_quest(ctx: Context<CreateQuest>, name: String, xp_reward: u64) -> Result<()> {
-        instructions::create_quest::handler(ctx, name, xp_reward)
+    pub fn create_quest(
+        ctx: Context<CreateQuest>,
+        name: String,
+        xp_reward: u64,
+        min_wealth_tier: u8,
+    ) -> Result<()> {
+        instructions::create_quest::handler(ctx, name, xp_reward, min_wealth_tier)
+    }
+
+    /// Create a live top-N leaderboard for an Event
+    pub fn create_leaderboard(ctx: Context<CreateLeaderboard>) -> Result<()> {
+        instructions::create_leaderboard::handler(ctx)
     }
 
     /// Scanner triggers this when a user completes a quest at an event
@@ -45,6 +118,152 @@ pub mod aeterna {
     pub fn grant_xp(ctx: Context<GrantXp>, args: GrantXpArgs) -> Result<()> {
         instructions::grant_xp::handler(ctx, args)
     }
+
+    /// Close a `grant_xp` idempotency receipt once its retention period has passed
+    pub fn close_grant_receipt(ctx: Context<CloseGrantReceipt>) -> Result<()> {
+        instructions::close_grant_receipt::handler(ctx)
+    }
+
+    /// Credit XP for the allowlisted on-chain action right before it (owner signed, one per transaction)
+    pub fn claim_action_xp(ctx: Context<ClaimActionXp>) -> Result<()> {
+        instructions::claim_action_xp::handler(ctx)
+    }
+
+    /// Register an action type and the programs that count towards it (admin only)
+    pub fn create_action_type(ctx: Context<CreateActionType>, args: ActionTypeArgs) -> Result<()> {
+        instructions::manage_actions::create_handler(ctx, args)
+    }
+
+    /// Change an action type's XP table entry, programs, cooldown or status (admin only)
+    pub fn update_action_type(ctx: Context<UpdateActionType>, args: UpdateActionTypeArgs) -> Result<()> {
+        instructions::manage_actions::update_handler(ctx, args)
+    }
+
+    /// Bootstrap the protocol config with the level ladder
+    pub fn initialize_config(ctx: Context<InitializeConfig>, levels: Vec<LevelTier>) -> Result<()> {
+        instructions::manage_config::initialize_handler(ctx, levels)
+    }
+
+    /// Replace the level ladder (admin only)
+    pub fn update_level_table(ctx: Context<UpdateConfig>, levels: Vec<LevelTier>) -> Result<()> {
+        instructions::manage_config::update_levels_handler(ctx, levels)
+    }
+
+    /// Set trading volume thresholds for wealth tiers (admin only)
+    pub fn update_wealth_tiers(ctx: Context<UpdateConfig>, args: WealthTierArgs) -> Result<()> {
+        instructions::manage_config::update_wealth_tiers_handler(ctx, args)
+    }
+
+    /// Set the per-credit and per-soul daily XP caps every XP source obeys (admin only, 0 = unlimited)
+    pub fn update_xp_caps(ctx: Context<UpdateConfig>, args: XpCapArgs) -> Result<()> {
+        instructions::manage_config::update_xp_caps_handler(ctx, args)
+    }
+
+    /// Set the stablecoin `pulse_pay` accepts and the merchant XP rate cap (admin only)
+    pub fn update_payment_config(ctx: Context<UpdateConfig>, args: PaymentConfigArgs) -> Result<()> {
+        instructions::manage_config::update_payment_config_handler(ctx, args)
+    }
+
+    /// Create the action-type XP table used by `grant_xp` (admin only)
+    pub fn initialize_xp_table(ctx: Context<InitializeXpTable>, entries: Vec<XpTableEntry>) -> Result<()> {
+        instructions::manage_config::initialize_xp_table_handler(ctx, entries)
+    }
+
+    /// Replace the action-type XP table (admin only)
+    pub fn update_xp_table(ctx: Context<UpdateXpTable>, entries: Vec<XpTableEntry>) -> Result<()> {
+        instructions::manage_config::update_xp_table_handler(ctx, entries)
+    }
+
+    /// Upgrade a legacy-layout account in place (realloc + version stamp).
+    /// Accounts that grew appended fields cannot be loaded by any other instruction until
+    /// migrated: a v1 `PulseLink` fails every Pulse instruction (re-registering included),
+    /// and a v1 `ProtocolConfig` fails everything that reads the config.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
+
+    /// Create the Core collection that soulbound achievement badges are minted into (admin only)
+    pub fn initialize_badge_collection(
+        ctx: Context<InitializeBadgeCollection>,
+        args: BadgeCollectionArgs,
+    ) -> Result<()> {
+        instructions::manage_achievements::initialize_collection_handler(ctx, args)
+    }
+
+    /// Define a milestone achievement and its criteria (admin only)
+    pub fn create_achievement(ctx: Context<CreateAchievement>, args: AchievementArgs) -> Result<()> {
+        instructions::manage_achievements::create_handler(ctx, args)
+    }
+
+    /// Mint a soulbound badge to the pass owner once the achievement's criteria are met
+    pub fn claim_achievement(ctx: Context<ClaimAchievement>) -> Result<()> {
+        instructions::claim_achievement::handler(ctx)
+    }
+
+    /// Opt an event into POAP-style attendance proofs by creating its Core collection (organizer only)
+    pub fn enable_attendance(ctx: Context<EnableAttendance>, args: AttendanceArgs) -> Result<()> {
+        instructions::manage_attendance::enable_handler(ctx, args)
+    }
+
+    /// Mint a frozen attendance proof to the pass owner, without a gate scan (a first `check_in` also mints one)
+    pub fn record_attendance(ctx: Context<RecordAttendance>) -> Result<()> {
+        instructions::record_attendance::handler(ctx)
+    }
+
+    /// Add an item to an event's points reward catalog (organizer only)
+    pub fn create_reward(ctx: Context<CreateReward>, args: RewardArgs) -> Result<()> {
+        instructions::create_reward::handler(ctx, args)
+    }
+
+    /// Change a reward's cost or stock, or retire and reactivate it (organizer only)
+    pub fn update_reward(ctx: Context<UpdateReward>, args: UpdateRewardArgs) -> Result<()> {
+        instructions::create_reward::update_handler(ctx, args)
+    }
+
+    /// Spend a soul's points on a reward and write a numbered Redemption receipt
+    pub fn redeem_reward(ctx: Context<RedeemReward>) -> Result<()> {
+        instructions::redeem_reward::handler(ctx)
+    }
+
+    /// Mark a Redemption receipt as served (event staff only)
+    pub fn fulfill_redemption(ctx: Context<FulfillRedemption>) -> Result<()> {
+        instructions::fulfill_redemption::handler(ctx)
+    }
+
+    /// Create a merkle-distributor airdrop and its token vault (admin only)
+    pub fn create_airdrop(ctx: Context<CreateAirdrop>, args: AirdropArgs) -> Result<()> {
+        instructions::create_airdrop::handler(ctx, args)
+    }
+
+    /// Claim a pass's airdrop allocation with a merkle proof, gated by soul stage
+    pub fn claim_airdrop(ctx: Context<ClaimAirdrop>, args: ClaimAirdropArgs) -> Result<()> {
+        instructions::claim_airdrop::handler(ctx, args)
+    }
+
+    /// Reclaim an airdrop's unclaimed tokens and close it to further claims (admin only)
+    pub fn sweep_airdrop(ctx: Context<SweepAirdrop>) -> Result<()> {
+        instructions::create_airdrop::sweep_handler(ctx)
+    }
+
+    /// Set whether passes may re-enter after checking out (organizer only)
+    pub fn set_reentry_policy(
+        ctx: Context<SetReentryPolicy>,
+        policy: ReentryPolicy,
+        cooldown: u32,
+    ) -> Result<()> {
+        instructions::manage_attendance::set_reentry_policy_handler(ctx, policy, cooldown)
+    }
+
+    /// Scan a pass in at the venue gate; fails if it is already inside. A soul's first entry
+    /// at an event with attendance proofs enabled also mints its proof.
+    pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
+        instructions::check_in::handler(ctx)
+    }
+
+    /// Scan a pass out at the venue gate
+    pub fn check_out(ctx: Context<CheckOut>) -> Result<()> {
+        instructions::check_out::handler(ctx)
+    }
 }
 
 #[error_code]
@@ -59,4 +278,141 @@ pub enum AeternaError {
     InvalidStage,
     #[msg("This Soul is Dormant and cannot earn XP. Activate it first.")]
     NotActivated,
+    #[msg("Attribute key is reserved for the protocol.")]
+    ReservedAttributeKey,
+    #[msg("Attribute key is not in the allowed schema.")]
+    UnknownAttributeKey,
+    #[msg("Attribute key appears more than once.")]
+    DuplicateAttributeKey,
+    #[msg("Attribute key exceeds the maximum length.")]
+    AttributeKeyTooLong,
+    #[msg("Attribute value exceeds the maximum length.")]
+    AttributeValueTooLong,
+    #[msg("Too many custom attributes supplied.")]
+    TooManyAttributes,
+    #[msg("Account is not owned by the AETERNA program.")]
+    InvalidAccountOwner,
+    #[msg("Account type is not recognised or cannot be migrated.")]
+    UnknownAccountType,
+    #[msg("Account is already at the current layout version.")]
+    AlreadyMigrated,
+    #[msg("Level table must start at 0 XP, ascend strictly and fit the config limits.")]
+    InvalidLevelTable,
+    #[msg("Wealth tier thresholds must ascend and the required tier must exist.")]
+    InvalidWealthTiers,
+    #[msg("Wealth tier too low for this action.")]
+    WealthTierTooLow,
+    #[msg("An event-scoped grant needs the soul's participation record for that event.")]
+    ParticipationRequired,
+    #[msg("Achievement name, URI or criteria are invalid.")]
+    InvalidAchievement,
+    #[msg("This soul does not meet the achievement's criteria.")]
+    AchievementCriteriaNotMet,
+    #[msg("All badges for this achievement have been claimed.")]
+    AchievementSoldOut,
+    #[msg("Badge collection is not set or does not match the config.")]
+    BadgeCollectionNotSet,
+    #[msg("Attendance proofs are not enabled for this event.")]
+    AttendanceNotEnabled,
+    #[msg("Attendance proofs are already enabled for this event.")]
+    AttendanceAlreadyEnabled,
+    #[msg("Reward name or cost is invalid.")]
+    InvalidReward,
+    #[msg("This reward is not currently available.")]
+    RewardInactive,
+    #[msg("This reward is out of stock.")]
+    RewardOutOfStock,
+    #[msg("Soul stage too low for this action.")]
+    StageTooLow,
+    #[msg("Not enough points for this reward.")]
+    InsufficientPoints,
+    #[msg("This redemption has already been fulfilled.")]
+    AlreadyFulfilled,
+    #[msg("Airdrop name or leaf count is invalid.")]
+    InvalidAirdrop,
+    #[msg("Merkle proof does not match the airdrop root.")]
+    InvalidMerkleProof,
+    #[msg("This airdrop allocation has already been claimed.")]
+    AirdropAlreadyClaimed,
+    #[msg("This pass is already checked in.")]
+    AlreadyCheckedIn,
+    #[msg("This pass is not checked in.")]
+    NotCheckedIn,
+    #[msg("This event does not allow re-entry.")]
+    ReentryNotAllowed,
+    #[msg("Re-entry cooldown has not elapsed since check-out.")]
+    ReentryCooldown,
+    #[msg("Pulse link was made by a previous owner of this pass.")]
+    PulseLinkInvalid,
+    #[msg("Per-transaction cap must not exceed the daily cap, and the allowlist must fit.")]
+    InvalidSpendLimits,
+    #[msg("Spend exceeds the Pulse wallet's per-transaction or daily cap.")]
+    SpendLimitExceeded,
+    #[msg("Recipient is not on the Pulse wallet's allowlist.")]
+    RecipientNotAllowed,
+    #[msg("Merchant name or fee is invalid.")]
+    InvalidMerchant,
+    #[msg("This merchant is not accepting payments.")]
+    MerchantInactive,
+    #[msg("Boost window name, times, multiplier or scope is invalid.")]
+    InvalidBoostWindow,
+    #[msg("Grant receipt is still within its retention period.")]
+    ReceiptRetained,
+    #[msg("Per-grant XP cap must not exceed the daily XP cap.")]
+    InvalidXpCaps,
+    #[msg("Grant exceeds the per-grant XP cap.")]
+    GrantTooLarge,
+    #[msg("Grant exceeds this soul's daily XP cap.")]
+    DailyXpCapExceeded,
+    #[msg("Action type name, program allowlist or cooldown is invalid.")]
+    InvalidActionType,
+    #[msg("This action type is not claimable.")]
+    ActionInactive,
+    #[msg("The instruction before this claim is not an allowlisted one signed by the pass owner.")]
+    ActionNotFound,
+    #[msg("Action XP was claimed too recently.")]
+    ActionCooldown,
+    #[msg("XP table has too many entries, a bad name, weight or max count, or duplicate action ids.")]
+    InvalidXpTable,
+    #[msg("Action type is not in the XP table.")]
+    UnknownAction,
+    #[msg("Pulse payments only accept the protocol's approved stablecoin.")]
+    MintNotApproved,
+    #[msg("Merchant XP rate exceeds the protocol's max XP per unit.")]
+    XpRateTooHigh,
+    #[msg("Only one top-level action XP claim is allowed per transaction.")]
+    DuplicateActionClaim,
+    #[msg("Action count must be between 1 and the XP table entry's max count.")]
+    InvalidActionCount,
+    #[msg("A first check-in at an event with attendance proofs must include the proof accounts.")]
+    AttendanceProofRequired,
+    #[msg("This airdrop has been swept and no longer accepts claims.")]
+    AirdropClosed,
+    #[msg("Event-scoped XP must also pass the event's leaderboard address.")]
+    LeaderboardRequired,
+}
+
+impl From<aeterna_rules::stage::EvolutionError> for AeternaError {
+    fn from(err: aeterna_rules::stage::EvolutionError) -> Self {
+        use aeterna_rules::stage::EvolutionError;
+        match err {
+            EvolutionError::InvalidStage => AeternaError::InvalidStage,
+            EvolutionError::NotEnoughXp => AeternaError::NotEnoughXp,
+            EvolutionError::WealthTierTooLow => AeternaError::WealthTierTooLow,
+        }
+    }
+}
+
+impl From<aeterna_rules::attributes::AttributeError> for AeternaError {
+    fn from(err: aeterna_rules::attributes::AttributeError) -> Self {
+        use aeterna_rules::attributes::AttributeError;
+        match err {
+            AttributeError::TooManyAttributes => AeternaError::TooManyAttributes,
+            AttributeError::KeyTooLong => AeternaError::AttributeKeyTooLong,
+            AttributeError::ValueTooLong => AeternaError::AttributeValueTooLong,
+            AttributeError::ReservedKey => AeternaError::ReservedAttributeKey,
+            AttributeError::UnknownKey => AeternaError::UnknownAttributeKey,
+            AttributeError::DuplicateKey => AeternaError::DuplicateAttributeKey,
+        }
+    }
 }
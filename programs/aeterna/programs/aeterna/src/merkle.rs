@@ -0,0 +1,28 @@
+//! Merkle proofs for airdrop distributions.
+//!
+//! Leaves are `keccak(0x00 ++ index ++ asset ++ amount)` and nodes are
+//! `keccak(0x01 ++ min(a, b) ++ max(a, b))`, so proofs need no left/right flags and a
+//! leaf can never be passed off as an inner node. Off-chain tree builders must use
+//! these same functions.
+
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hash of one `(index, asset, amount)` allocation
+pub fn leaf(index: u32, asset: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[LEAF_PREFIX, &index.to_le_bytes(), asset.as_ref(), &amount.to_le_bytes()]).0
+}
+
+/// Parent of two sibling nodes (order-independent)
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[NODE_PREFIX, first, second]).0
+}
+
+/// Whether `proof` links `leaf` to `root`
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
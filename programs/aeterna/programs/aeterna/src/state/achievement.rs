@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::state::event_participation::EventParticipation;
+use crate::state::soul_stats::SoulStats;
+
+/// A SoulStats (or EventParticipation) field an achievement can be gated on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatField {
+    Xp,
+    QuestsCompleted,
+    Stage,
+    Level,
+    TradingVolume,
+    WealthTier,
+    /// Requires the achievement to be scoped to an event
+    EventXp,
+    /// Requires the achievement to be scoped to an event
+    EventQuestsCompleted,
+}
+
+/// `field >= min`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct Criterion {
+    pub field: StatField,
+    pub min: u64,
+}
+
+/// A milestone definition (e.g. "10 quests", "Ascended at Festival X").
+/// Seeds: ["achievement", name]
+#[account]
+pub struct Achievement {
+    /// Badge name, also used as the badge asset name
+    pub name: String,
+    /// Metadata URI for minted badges
+    pub uri: String,
+    /// All criteria must hold at claim time
+    pub criteria: Vec<Criterion>,
+    /// If set, the soul must have an EventParticipation at this event
+    pub event: Option<Pubkey>,
+    /// Maximum number of badges (0 = unlimited) — e.g. "first 100 Ascended"
+    pub max_supply: u32,
+    /// Badges minted so far
+    pub claimed: u32,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Achievement {
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_CRITERIA: usize = 4;
+
+    /// 8 (discriminator) + (4 + 32) (name) + (4 + 200) (uri) + 4 + 4 * (1 + 8) (criteria)
+    /// + 33 (event) + 4 (max_supply) + 4 (claimed) + 1 (bump) + 1 (version) + 32 (reserved) = 367
+    pub const SIZE: usize = 8
+        + (4 + MAX_SEED_NAME_LEN)
+        + (4 + Self::MAX_URI_LEN)
+        + 4 + Self::MAX_CRITERIA * (1 + 8)
+        + 33
+        + 4
+        + 4
+        + 1
+        + 1
+        + 32;
+    pub const VERSION: u8 = 1;
+
+    /// Whether every criterion holds for this soul
+    pub fn is_met(&self, stats: &SoulStats, participation: Option<&EventParticipation>) -> bool {
+        self.criteria.iter().all(|criterion| {
+            let value = match criterion.field {
+                StatField::Xp => Some(stats.xp),
+                StatField::QuestsCompleted => Some(stats.quests_completed as u64),
+                StatField::Stage => Some(stats.current_stage as u64),
+                StatField::Level => Some(stats.level as u64),
+                StatField::TradingVolume => Some(stats.trading_volume),
+                StatField::WealthTier => Some(stats.wealth_tier as u64),
+                StatField::EventXp => participation.map(|p| p.event_xp),
+                StatField::EventQuestsCompleted => participation.map(|p| p.quests_completed as u64),
+            };
+            value.map_or(false, |value| value >= criterion.min)
+        })
+    }
+}
+
+/// Proof that a pass has claimed an achievement — `init` makes claims one-shot.
+/// Seeds: ["achievement_claim", achievement.key(), asset.key()]
+#[account]
+pub struct AchievementClaim {
+    pub achievement: Pubkey,
+    /// The pass that earned the badge
+    pub asset: Pubkey,
+    /// The minted soulbound badge asset
+    pub badge: Pubkey,
+    /// Unix seconds
+    pub claimed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl AchievementClaim {
+    /// 8 + 32 + 32 + 32 + 8 + 1 + 1 + 32 = 146
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+use anchor_lang::solana_program::instruction::Instruction;
+
+/// An on-chain action (swap, stake, vote...) that earns XP through `claim_action_xp`.
+/// Seeds: ["action_type", name]
+#[account]
+pub struct ActionType {
+    /// Human readable name (e.g. "swap")
+    pub name: String,
+    /// `XpTable` entry whose `xp_per_action` is credited per claim, so on-chain claims and
+    /// backend-reported `grant_xp` actions are priced from one table
+    pub xp_action: u8,
+    /// Programs whose instructions count as this action (e.g. a DEX or staking program)
+    pub programs: Vec<Pubkey>,
+    /// Minimum seconds between claims by the same soul (always non-zero)
+    pub cooldown: u32,
+    /// Whether the action can currently be claimed
+    pub active: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl ActionType {
+    pub const MAX_PROGRAMS: usize = 4;
+
+    /// 8 (discriminator) + (4 + 32) (name) + 1 (xp_action) + 4 + 4 * 32 (programs) + 4 (cooldown)
+    /// + 1 (active) + 1 (bump) + 1 (version) + 32 (reserved) = 216
+    pub const SIZE: usize = 8 + (4 + MAX_SEED_NAME_LEN) + 1 + 4 + Self::MAX_PROGRAMS * 32 + 4 + 1 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+
+    /// An instruction counts if it targets an allowlisted program and `owner` signed it
+    pub fn matches(&self, ix: &Instruction, owner: &Pubkey) -> bool {
+        self.programs.contains(&ix.program_id)
+            && ix.accounts.iter().any(|meta| meta.is_signer && meta.pubkey == *owner)
+    }
+}
+
+/// A soul's claim history for one action type, used to enforce the cooldown.
+/// Seeds: ["action_claim", action.key(), asset.key()]
+#[account]
+pub struct ActionClaim {
+    pub action: Pubkey,
+    /// The pass credited
+    pub asset: Pubkey,
+    /// Claims so far
+    pub count: u32,
+    /// Most recent claim (Unix seconds)
+    pub last_claimed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl ActionClaim {
+    /// 8 + 32 + 32 + 4 + 8 + 1 + 1 + 32 = 118
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 8 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+
+/// A merkle-distributor airdrop to passes, paid from an SPL token vault.
+/// Seeds: ["airdrop", name]
+/// The vault is the token account at ["airdrop_vault", airdrop.key()], owned by this PDA.
+#[account]
+pub struct Airdrop {
+    /// Protocol admin that created the airdrop
+    pub authority: Pubkey,
+    /// Human readable name, also the PDA seed
+    pub name: String,
+    /// SPL mint being distributed
+    pub mint: Pubkey,
+    /// Token account holding the undistributed supply
+    pub vault: Pubkey,
+    /// Root of the (index, asset, amount) tree — see `crate::merkle`
+    pub merkle_root: [u8; 32],
+    /// Minimum `SoulStats.current_stage` at claim time (e.g. 2 = Ascended only)
+    pub min_stage: u8,
+    /// Number of leaves in the tree; sizes the claimed bitmap
+    pub num_leaves: u32,
+    /// Leaves claimed so far
+    pub claimed_count: u32,
+    /// Tokens paid out so far
+    pub claimed_amount: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Set by `sweep_airdrop` once the unclaimed supply is reclaimed; no claims after that
+    pub swept: bool,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 31],
+    /// One bit per leaf index, set once claimed
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl Airdrop {
+    /// Keeps the account under the 10 KiB CPI allocation limit
+    pub const MAX_LEAVES: u32 = 65_536;
+
+    /// 8 (discriminator) + 32 (authority) + (4 + 32) (name) + 32 (mint) + 32 (vault) + 32 (merkle_root)
+    /// + 1 (min_stage) + 4 (num_leaves) + 4 (claimed_count) + 8 (claimed_amount) + 1 (bump) + 1 (version)
+    /// + 1 (swept) + 31 (reserved) + 4 (bitmap length) = 226, plus one byte per 8 leaves
+    pub const BASE_SIZE: usize = 8 + 32 + (4 + MAX_SEED_NAME_LEN) + 32 + 32 + 32 + 1 + 4 + 4 + 8 + 1 + 1 + 1 + 31 + 4;
+    pub const VERSION: u8 = 1;
+
+    pub fn space(num_leaves: u32) -> usize {
+        Self::BASE_SIZE + Self::bitmap_len(num_leaves)
+    }
+
+    pub fn bitmap_len(num_leaves: u32) -> usize {
+        (num_leaves as usize + 7) / 8
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed_bitmap[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed_bitmap[index as usize / 8] |= 1 << (index % 8);
+    }
+}
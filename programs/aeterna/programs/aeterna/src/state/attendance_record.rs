@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Proof that a soul was checked in at an event — one per (event, asset).
+/// Seeds: ["attendance", event.key(), asset.key()]
+/// Anyone can verify attendance from a pass by deriving this PDA.
+#[account]
+pub struct AttendanceRecord {
+    /// The Event
+    pub event: Pubkey,
+    /// The pass (Core asset)
+    pub asset: Pubkey,
+    /// The pass owner at check-in, who received the proof
+    pub owner: Pubkey,
+    /// The minted attendance asset
+    pub proof: Pubkey,
+    /// Check-in time (Unix seconds)
+    pub checked_in_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl AttendanceRecord {
+    /// 8 (discriminator) + 32 (event) + 32 (asset) + 32 (owner) + 32 (proof)
+    /// + 8 (checked_in_at) + 1 (bump) + 1 (version) + 32 (reserved) = 178
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+}
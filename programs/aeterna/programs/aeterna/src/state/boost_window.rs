@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+
+/// What a boost window multiplies
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum BoostScope {
+    /// Every quest and merchant at the event
+    Event,
+    /// Only these quests
+    Quests(Vec<Pubkey>),
+    /// Only spend at this merchant
+    Merchant(Pubkey),
+}
+
+/// A timed XP multiplier at an event (e.g. "2x XP at the Main Bar from 18:00-19:00").
+/// Seeds: ["boost", event.key(), name]
+#[account]
+pub struct BoostWindow {
+    /// The Event this window belongs to
+    pub event: Pubkey,
+    /// Human readable name (e.g. "Happy Hour")
+    pub name: String,
+    /// Window opens (Unix seconds, inclusive)
+    pub start: i64,
+    /// Window closes (Unix seconds, exclusive)
+    pub end: i64,
+    /// XP multiplier in basis points (20_000 = 2x)
+    pub multiplier_bps: u16,
+    /// Quests or merchant the multiplier applies to
+    pub scope: BoostScope,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl BoostWindow {
+    pub const MAX_QUESTS: usize = 8;
+    /// Boosts never reduce XP
+    pub const MIN_MULTIPLIER_BPS: u16 = aeterna_rules::xp::BPS_BASE as u16;
+    /// 5x — keeps a misconfigured window from flooding the level ladder
+    pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+
+    /// 8 (discriminator) + 32 (event) + (4 + 32) (name) + 8 (start) + 8 (end) + 2 (multiplier_bps)
+    /// + (1 + 4 + 8 * 32) (scope) + 1 (bump) + 1 (version) + 32 (reserved) = 389
+    pub const SIZE: usize = 8
+        + 32
+        + (4 + MAX_SEED_NAME_LEN)
+        + 8
+        + 8
+        + 2
+        + (1 + 4 + Self::MAX_QUESTS * 32)
+        + 1
+        + 1
+        + 32;
+    pub const VERSION: u8 = 1;
+
+    pub fn is_live(&self, now: i64) -> bool {
+        self.start <= now && now < self.end
+    }
+
+    /// The multiplier for completing `quest` at `now`, if this window covers it
+    pub fn quest_multiplier(&self, quest: &Pubkey, now: i64) -> Option<u16> {
+        let in_scope = match &self.scope {
+            BoostScope::Event => true,
+            BoostScope::Quests(quests) => quests.contains(quest),
+            BoostScope::Merchant(_) => false,
+        };
+        (in_scope && self.is_live(now)).then_some(self.multiplier_bps)
+    }
+
+    /// The multiplier for spend at `merchant` (trading at `merchant_event`) at `now`,
+    /// if this window covers it
+    pub fn merchant_multiplier(&self, merchant: &Pubkey, merchant_event: &Pubkey, now: i64) -> Option<u16> {
+        let in_scope = self.event == *merchant_event
+            && match &self.scope {
+                BoostScope::Event => true,
+                BoostScope::Quests(_) => false,
+                BoostScope::Merchant(scoped) => scoped == merchant,
+            };
+        (in_scope && self.is_live(now)).then_some(self.multiplier_bps)
+    }
+}
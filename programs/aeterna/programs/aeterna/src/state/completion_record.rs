@@ -13,9 +13,14 @@ pub struct CompletionRecord {
     pub completed_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (0 = legacy layout)
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
 }
 
 impl CompletionRecord {
-    /// 8 (discriminator) + 32 (quest) + 32 (asset) + 8 (completed_at) + 1 (bump) = 81
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+    /// 8 (discriminator) + 32 (quest) + 32 (asset) + 8 (completed_at) + 1 (bump) + 1 (version) + 32 (reserved) = 114
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
 }
@@ -1,5 +1,16 @@
 use anchor_lang::prelude::*;
 
+/// Gate re-entry policy. The zero variant is the default for legacy accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReentryPolicy {
+    /// Passes may leave and re-enter freely
+    Unlimited,
+    /// One entry per pass
+    NoReentry,
+    /// Re-entry allowed once `reentry_cooldown` seconds have passed since check-out
+    Cooldown,
+}
+
 #[account]
 pub struct Event {
     /// The authority/admin of this event (the organizer)
@@ -10,6 +21,17 @@ pub struct Event {
     pub active: bool,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Account layout version (0 = legacy layout)
+    pub version: u8,
+    /// Core collection that attendance proofs are minted into (default = attendance proofs disabled).
+    /// Its URI is the event-branded metadata every proof shares.
+    pub attendance_collection: Pubkey,
+    /// Whether a pass may check in again after checking out
+    pub reentry_policy: ReentryPolicy,
+    /// Minimum seconds between check-out and re-entry under `ReentryPolicy::Cooldown`
+    pub reentry_cooldown: u32,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 27],
 }
 
 impl Event {
@@ -17,5 +39,13 @@ impl Event {
     // Adjusting name size to be reasonable buffer if needed, but String is dynamic in Anchor now (heap).
     // For fixed size/zero copy we'd need slice. For now standard account is fine.
     // Let's allocate enough for a reasonable name.
-    pub const SIZE: usize = 8 + 32 + (4 + 64) + 1 + 1; 
+    // + 1 version + 32 attendance_collection + 1 reentry_policy + 4 reentry_cooldown + 27 reserved
+    pub const SIZE: usize = 8 + 32 + (4 + 64) + 1 + 1 + 1 + 32 + 1 + 4 + 27;
+    pub const VERSION: u8 = 1;
+}
+
+impl Event {
+    pub fn attendance_enabled(&self) -> bool {
+        self.attendance_collection != Pubkey::default()
+    }
 }
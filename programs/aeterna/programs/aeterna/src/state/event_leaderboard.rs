@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct LeaderboardEntry {
+    /// The pass (Core asset)
+    pub asset: Pubkey,
+    /// XP earned at this event
+    pub xp: u64,
+}
+
+/// Live top-N board of souls by XP earned at a single event, sorted descending.
+/// Seeds: ["leaderboard", event.key()]
+#[account]
+pub struct EventLeaderboard {
+    /// The Event this board ranks
+    pub event: Pubkey,
+    /// Sorted by `xp` descending; ties keep their earlier position
+    pub entries: Vec<LeaderboardEntry>,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl EventLeaderboard {
+    pub const MAX_ENTRIES: usize = 50;
+
+    /// 8 (discriminator) + 32 (event) + 4 + 50 * (32 + 8) (entries) + 1 (bump) + 1 (version) + 32 (reserved) = 2078
+    pub const SIZE: usize = 8 + 32 + 4 + Self::MAX_ENTRIES * (32 + 8) + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+
+    /// Set `asset`'s event XP total (from its `EventParticipation`), keeping the board
+    /// sorted and bounded. A soul not on a full board only enters if it beats the last entry.
+    pub fn record(&mut self, asset: Pubkey, event_xp: u64) {
+        let position = self.entries.iter().position(|entry| entry.asset == asset);
+        match position {
+            Some(i) => self.entries[i].xp = event_xp,
+            None if self.entries.len() < Self::MAX_ENTRIES => {
+                self.entries.push(LeaderboardEntry { asset, xp: event_xp });
+            }
+            None => match self.entries.last_mut() {
+                Some(last) if event_xp > last.xp => *last = LeaderboardEntry { asset, xp: event_xp },
+                _ => return,
+            },
+        }
+        // Stable sort: only one entry changed, so this is effectively an insertion pass
+        self.entries.sort_by_key(|entry| std::cmp::Reverse(entry.xp));
+    }
+
+    /// `record` into the board at `info` if `create_leaderboard` has run for its event.
+    /// An uninitialized board PDA is skipped, so XP instructions always take the address
+    /// and a board created mid-event can never be left stale by a caller omitting it.
+    pub fn record_if_created(info: &AccountInfo, asset: Pubkey, event_xp: u64) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let mut board = Self::try_deserialize(&mut &data[..])?;
+        board.record(asset, event_xp);
+        let mut writer: &mut [u8] = &mut data[..];
+        board.try_serialize(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> EventLeaderboard {
+        EventLeaderboard {
+            event: Pubkey::new_unique(),
+            entries: Vec::new(),
+            bump: 255,
+            version: EventLeaderboard::VERSION,
+            reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn ranks_by_event_total_not_latest_increment() {
+        let mut board = board();
+        let (veteran, newcomer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        // Totals come from EventParticipation: 100 then 150 after a further +50 grant
+        board.record(veteran, 100);
+        board.record(newcomer, 120);
+        board.record(veteran, 150);
+
+        let ranked: Vec<_> = board.entries.iter().map(|e| (e.asset, e.xp)).collect();
+        assert_eq!(ranked, vec![(veteran, 150), (newcomer, 120)]);
+    }
+
+    #[test]
+    fn full_board_only_admits_higher_totals() {
+        let mut board = board();
+        for xp in 1..=EventLeaderboard::MAX_ENTRIES as u64 {
+            board.record(Pubkey::new_unique(), xp * 10);
+        }
+        let low = Pubkey::new_unique();
+        board.record(low, 5);
+        assert!(board.entries.iter().all(|e| e.asset != low));
+
+        let high = Pubkey::new_unique();
+        board.record(high, 1_000);
+        assert_eq!(board.entries.len(), EventLeaderboard::MAX_ENTRIES);
+        assert_eq!(board.entries[0].asset, high);
+        assert_eq!(board.entries.last().unwrap().xp, 20);
+    }
+
+    #[test]
+    fn ties_keep_earlier_position() {
+        let mut board = board();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        board.record(first, 50);
+        board.record(second, 50);
+        assert_eq!(board.entries[0].asset, first);
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+/// A soul's activity at a single event, separate from its lifetime SoulStats.
+/// Seeds: ["participation", event.key(), asset.key()]
+/// Created on the soul's first interaction with the event (pass mint or first quest).
+#[account]
+pub struct EventParticipation {
+    /// The Event
+    pub event: Pubkey,
+    /// The pass (Core asset)
+    pub asset: Pubkey,
+    /// XP earned at this event
+    pub event_xp: u64,
+    /// Quests completed at this event
+    pub quests_completed: u32,
+    /// First interaction (Unix seconds)
+    pub first_seen: i64,
+    /// Most recent interaction (Unix seconds)
+    pub last_seen: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Whether the pass is currently inside the venue
+    pub inside: bool,
+    /// Most recent gate check-in (Unix seconds, 0 = never)
+    pub last_check_in: i64,
+    /// Most recent gate check-out (Unix seconds, 0 = never)
+    pub last_check_out: i64,
+    /// Number of gate entries
+    pub entries: u32,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 43],
+}
+
+impl EventParticipation {
+    /// 8 (discriminator) + 32 (event) + 32 (asset) + 8 (event_xp) + 4 (quests_completed)
+    /// + 8 (first_seen) + 8 (last_seen) + 1 (bump) + 1 (version) + 1 (inside) + 8 (last_check_in)
+    /// + 8 (last_check_out) + 4 (entries) + 43 (reserved) = 166
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 4 + 8 + 8 + 1 + 1 + 1 + 8 + 8 + 4 + 43;
+    pub const VERSION: u8 = 1;
+
+    /// Stamp a visit. Initializes the record if this is the soul's first interaction
+    /// (the account was just created via `init_if_needed`). Returns true on first visit.
+    pub fn visit(&mut self, event: Pubkey, asset: Pubkey, bump: u8, now: i64) -> bool {
+        let first_visit = self.event == Pubkey::default();
+        if first_visit {
+            self.event = event;
+            self.asset = asset;
+            self.first_seen = now;
+            self.bump = bump;
+            self.version = Self::VERSION;
+        }
+        self.last_seen = now;
+        first_visit
+    }
+
+    pub fn add_xp(&mut self, xp: u64) {
+        self.event_xp = self.event_xp.saturating_add(xp);
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Records that an off-chain receipt (e.g. a hashed USDC transfer signature) has been credited.
+/// Seeds: ["grant_receipt", receipt_id]
+/// `grant_xp` inits this account, so a retried grant with the same receipt id fails with AccountAlreadyInUse.
+#[account]
+pub struct GrantReceipt {
+    /// The idempotency key supplied by the backend
+    pub receipt_id: [u8; 32],
+    /// The asset (pass) that was credited
+    pub asset: Pubkey,
+    /// XP credited, including merchant-rate and boosted XP
+    pub xp_amount: u64,
+    /// Timestamp of the grant (Unix seconds)
+    pub granted_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// `XpTable` action type credited
+    pub action: u8,
+    /// Number of actions credited
+    pub count: u32,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 27],
+}
+
+impl GrantReceipt {
+    /// 8 (discriminator) + 32 (receipt_id) + 32 (asset) + 8 (xp_amount) + 8 (granted_at) + 1 (bump)
+    /// + 1 (version) + 1 (action) + 4 (count) + 27 (reserved) = 122
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 1 + 4 + 27;
+    pub const VERSION: u8 = 1;
+    /// Receipts can be closed once they are older than any retry the backend would attempt (30 days)
+    pub const RETENTION_SECS: i64 = 30 * 24 * 60 * 60;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now.saturating_sub(self.granted_at) >= Self::RETENTION_SECS
+    }
+}
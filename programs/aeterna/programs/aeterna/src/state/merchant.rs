@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+use crate::state::protocol_config::ProtocolConfig;
+
+/// A vendor (bar, food stall, merch booth) at an event that accepts Pulse payments.
+/// Seeds: ["merchant", event.key(), name]
+#[account]
+pub struct Merchant {
+    /// The Event this merchant trades at
+    pub event: Pubkey,
+    /// Human readable name (e.g. "Main Bar")
+    pub name: String,
+    /// Token account that receives payments net of the protocol fee
+    pub payout: Pubkey,
+    /// Mint of `payout`, the token this merchant accepts
+    pub mint: Pubkey,
+    /// Decimals of `mint`, cached so XP can be computed without the mint account
+    pub decimals: u8,
+    /// XP a soul earns per whole token spent here, before `ProtocolConfig::max_xp_per_unit`
+    pub xp_per_unit: u64,
+    /// Protocol fee in basis points, set by the protocol admin
+    pub fee_bps: u16,
+    /// Whether the merchant currently accepts payments
+    pub active: bool,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Merchant {
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// 8 (discriminator) + 32 (event) + (4 + 32) (name) + 32 (payout) + 32 (mint) + 1 (decimals)
+    /// + 8 (xp_per_unit) + 2 (fee_bps) + 1 (active) + 1 (bump) + 1 (version) + 32 (reserved) = 186
+    pub const SIZE: usize = 8 + 32 + (4 + MAX_SEED_NAME_LEN) + 32 + 32 + 1 + 8 + 2 + 1 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+
+    /// Protocol's cut of a payment, rounded down
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_bps as u128 / Self::MAX_FEE_BPS as u128) as u64
+    }
+
+    /// `xp_per_unit` clamped to the config's current `max_xp_per_unit`, so lowering the cap
+    /// also applies to merchants registered before it
+    pub fn xp_rate(&self, config: &ProtocolConfig) -> u64 {
+        match config.max_xp_per_unit {
+            0 => self.xp_per_unit,
+            max => self.xp_per_unit.min(max),
+        }
+    }
+
+    /// XP earned for spending `amount` base units here
+    pub fn xp_for(&self, amount: u64, config: &ProtocolConfig) -> u64 {
+        aeterna_rules::xp::for_spend(amount, self.decimals, self.xp_rate(config))
+    }
+
+    /// XP earned for `volume` already normalized to volume units
+    pub fn xp_for_volume(&self, volume: u64, config: &ProtocolConfig) -> u64 {
+        aeterna_rules::xp::for_spend(volume, aeterna_rules::wealth::VOLUME_DECIMALS, self.xp_rate(config))
+    }
+}
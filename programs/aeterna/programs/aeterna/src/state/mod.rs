@@ -1,3 +1,6 @@
+/// Longest name an account may be keyed by: names are PDA seeds, and a seed is capped at 32 bytes
+pub const MAX_SEED_NAME_LEN: usize = 32;
+
 pub mod pulse_link;
 pub use pulse_link::*;
 pub mod event;
@@ -8,3 +11,27 @@ pub mod soul_stats;
 pub use soul_stats::*;
 pub mod completion_record;
 pub use completion_record::*;
+pub mod protocol_config;
+pub use protocol_config::*;
+pub mod event_leaderboard;
+pub use event_leaderboard::*;
+pub mod event_participation;
+pub use event_participation::*;
+pub mod achievement;
+pub use achievement::*;
+pub mod attendance_record;
+pub use attendance_record::*;
+pub mod reward;
+pub use reward::*;
+pub mod airdrop;
+pub use airdrop::*;
+pub mod merchant;
+pub use merchant::*;
+pub mod boost_window;
+pub use boost_window::*;
+pub mod grant_receipt;
+pub use grant_receipt::*;
+pub mod action_type;
+pub use action_type::*;
+pub mod xp_table;
+pub use xp_table::*;
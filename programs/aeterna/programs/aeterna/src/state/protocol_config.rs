@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// A single rung of the level ladder, mirroring the API's `XPEngine`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LevelTier {
+    /// Minimum lifetime XP to hold this level
+    pub min_xp: u64,
+    /// Display title written to the Core asset (e.g. "Seeker")
+    pub title: String,
+}
+
+/// Protocol-wide configuration, owned by the admin authority.
+/// Seeds: ["config"]
+#[account]
+pub struct ProtocolConfig {
+    /// Admin allowed to update the configuration
+    pub authority: Pubkey,
+    /// Level ladder, ascending by `min_xp`. Index 0 is level 1 and must start at 0 XP.
+    pub levels: Vec<LevelTier>,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Trading volume (volume units, see `aeterna_rules::wealth::VOLUME_DECIMALS`) required
+    /// for the Gold wealth tier (0 = tier disabled)
+    pub gold_min_volume: u64,
+    /// Trading volume (volume units) required for the Diamond wealth tier (0 = tier disabled)
+    pub diamond_min_volume: u64,
+    /// Minimum wealth tier required to evolve to Ascended (0 = no requirement)
+    pub ascend_min_wealth_tier: u8,
+    /// Core collection that soulbound achievement badges are minted into (default = unset)
+    pub badge_collection: Pubkey,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 15],
+    // ── v2: grant_xp rate limits, appended so `migrate_account` zero-fills them ──
+    /// Max XP a single credit may award, from any source (0 = unlimited)
+    pub max_grant_xp: u64,
+    /// Max XP one soul may earn per fixed 24h window, across every source (0 = unlimited)
+    pub daily_xp_cap: u64,
+    // ── v3: payments, appended so `migrate_account` zero-fills them ──
+    /// The only mint `pulse_pay` accepts (default = Pulse payments disabled)
+    pub stable_mint: Pubkey,
+    /// Highest `Merchant::xp_per_unit` an organizer may set (0 = unlimited)
+    pub max_xp_per_unit: u64,
+}
+
+impl ProtocolConfig {
+    pub const MAX_LEVELS: usize = 10;
+    pub const MAX_TITLE_LEN: usize = 24;
+
+    /// 8 (discriminator) + 32 (authority) + 4 + 10 * (8 + 4 + 24) (levels) + 1 (bump) + 1 (version)
+    /// + 8 (gold_min_volume) + 8 (diamond_min_volume) + 1 (ascend_min_wealth_tier)
+    /// + 32 (badge_collection) + 15 (reserved) + 8 (max_grant_xp) + 8 (daily_xp_cap)
+    /// + 32 (stable_mint) + 8 (max_xp_per_unit) = 526
+    pub const SIZE: usize = 8
+        + 32
+        + 4 + Self::MAX_LEVELS * (8 + 4 + Self::MAX_TITLE_LEN)
+        + 1
+        + 1
+        + 8
+        + 8
+        + 1
+        + 32
+        + 15
+        + 8
+        + 8
+        + 32
+        + 8;
+    pub const VERSION: u8 = 3;
+
+    /// 1-indexed level for `xp` — the highest tier whose threshold has been reached
+    pub fn level_for(&self, xp: u64) -> u8 {
+        aeterna_rules::level::level_for(self.levels.iter().map(|tier| tier.min_xp), xp)
+    }
+
+    /// Wealth tier for a cumulative trading volume (see `SoulStats::WEALTH_*`)
+    pub fn wealth_tier_for(&self, volume: u64) -> u8 {
+        aeterna_rules::wealth::tier_for(volume, self.gold_min_volume, self.diamond_min_volume)
+    }
+
+    /// Whether a merchant may award `xp_per_unit` XP per whole token
+    pub fn allows_xp_rate(&self, xp_per_unit: u64) -> bool {
+        self.max_xp_per_unit == 0 || xp_per_unit <= self.max_xp_per_unit
+    }
+
+    /// Title for a 1-indexed level
+    pub fn title_for(&self, level: u8) -> &str {
+        self.levels
+            .get((level as usize).saturating_sub(1))
+            .map(|tier| tier.title.as_str())
+            .unwrap_or("")
+    }
+}
@@ -5,8 +5,63 @@ pub struct PulseLink {
     pub asset: Pubkey,
     pub pulse_wallet: Pubkey,
     pub bump: u8,
+    /// Account layout version (0 = legacy layout)
+    pub version: u8,
+    /// The asset owner who created the link (default = legacy link, never valid)
+    pub owner: Pubkey,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+    // ── v2: spending limits, appended so `migrate_account` zero-fills them ──
+    /// Max tokens (base units) per program-mediated spend (0 = spending disabled)
+    pub per_tx_cap: u64,
+    /// Max tokens (base units) per fixed 24h window (0 = spending disabled)
+    pub daily_cap: u64,
+    /// Start of the current fixed 24h window (Unix seconds); a new window opens with the
+    /// first spend after this one expires
+    pub window_start: i64,
+    /// Spent so far in the current window
+    pub window_spent: u64,
+    /// Wallets (merchants) whose token accounts may receive spends
+    pub allowlist: Vec<Pubkey>,
 }
 
 impl PulseLink {
-    pub const SIZE: usize = 8 + 32 + 32 + 1;
+    pub const MAX_ALLOWLIST: usize = 8;
+    pub const SPEND_WINDOW: i64 = aeterna_rules::window::LENGTH;
+
+    /// 8 (discriminator) + 32 (asset) + 32 (pulse_wallet) + 1 (bump) + 1 (version) + 32 (owner)
+    /// + 32 (reserved) + 8 (per_tx_cap) + 8 (daily_cap) + 8 (window_start) + 8 (window_spent)
+    /// + 4 + 8 * 32 (allowlist) = 430
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 4 + Self::MAX_ALLOWLIST * 32;
+    pub const VERSION: u8 = 2;
+
+    /// A link only holds while the pass stays with the owner who made it —
+    /// once the asset is sold or transferred it must be treated as unlinked.
+    pub fn is_valid_for(&self, asset_owner: &Pubkey) -> bool {
+        self.owner != Pubkey::default() && self.owner == *asset_owner
+    }
+
+    /// Who gets the rent back when the link closes: the owner who made it, or `signer`
+    /// for a legacy link that never recorded one
+    pub fn rent_recipient(&self, signer: &Pubkey) -> Pubkey {
+        if self.owner == Pubkey::default() {
+            *signer
+        } else {
+            self.owner
+        }
+    }
+
+    /// Check a spend of `amount` to `recipient` against the owner's limits and record it.
+    /// The daily cap is a fixed window (`aeterna_rules::window`), not a rolling one.
+    pub fn record_spend(&mut self, recipient: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        require!(self.allowlist.contains(recipient), crate::AeternaError::RecipientNotAllowed);
+        require!(amount <= self.per_tx_cap, crate::AeternaError::SpendLimitExceeded);
+
+        let (start, spent) =
+            aeterna_rules::window::record(self.window_start, self.window_spent, amount, Some(self.daily_cap), now)
+                .ok_or(crate::AeternaError::SpendLimitExceeded)?;
+        self.window_start = start;
+        self.window_spent = spent;
+        Ok(())
+    }
 }
@@ -10,9 +10,16 @@ pub struct Quest {
     pub xp_reward: u64,
     /// Bump seed
     pub bump: u8,
+    /// Account layout version (0 = legacy layout)
+    pub version: u8,
+    /// Minimum wealth tier required to complete this quest (0 = open to all)
+    pub min_wealth_tier: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 63],
 }
 
 impl Quest {
-    // 8 discriminator + 32 event + (4 + 64 name) + 8 xp + 1 bump
-    pub const SIZE: usize = 8 + 32 + (4 + 64) + 8 + 1;
+    // 8 discriminator + 32 event + (4 + 64 name) + 8 xp + 1 bump + 1 version + 1 min_wealth_tier + 63 reserved
+    pub const SIZE: usize = 8 + 32 + (4 + 64) + 8 + 1 + 1 + 1 + 63;
+    pub const VERSION: u8 = 1;
 }
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::MAX_SEED_NAME_LEN;
+
+/// An item in an event's reward catalog (drink, merch, upgrade) bought with points.
+/// Seeds: ["reward", event.key(), name]
+#[account]
+pub struct Reward {
+    /// The Event whose organizer manages this reward
+    pub event: Pubkey,
+    /// Human readable name (e.g. "Free Drink")
+    pub name: String,
+    /// Points deducted per redemption
+    pub cost: u64,
+    /// Remaining stock (None = unlimited)
+    pub stock: Option<u32>,
+    /// Minimum evolution stage required to redeem (0 = open to all)
+    pub min_stage: u8,
+    /// Whether the reward can currently be redeemed
+    pub active: bool,
+    /// Redemptions so far — also the serial of the next Redemption receipt
+    pub redeemed: u32,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Reward {
+    /// 8 (discriminator) + 32 (event) + (4 + 32) (name) + 8 (cost) + 5 (stock) + 1 (min_stage)
+    /// + 1 (active) + 4 (redeemed) + 1 (bump) + 1 (version) + 32 (reserved) = 129
+    pub const SIZE: usize = 8 + 32 + (4 + MAX_SEED_NAME_LEN) + 8 + (1 + 4) + 1 + 1 + 4 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+
+    pub fn in_stock(&self) -> bool {
+        self.stock.map_or(true, |stock| stock > 0)
+    }
+}
+
+/// Receipt for one redemption, shown to bar/merch staff and marked fulfilled when served.
+/// Seeds: ["redemption", reward.key(), serial (u32 LE)]
+#[account]
+pub struct Redemption {
+    /// The Reward redeemed
+    pub reward: Pubkey,
+    /// The pass whose points paid for it
+    pub asset: Pubkey,
+    /// The pass owner who redeemed
+    pub owner: Pubkey,
+    /// Points deducted
+    pub cost: u64,
+    /// Sequential number within the reward
+    pub serial: u32,
+    /// Redemption time (Unix seconds)
+    pub redeemed_at: i64,
+    /// When staff handed the reward over (0 = not yet fulfilled)
+    pub fulfilled_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Redemption {
+    /// 8 (discriminator) + 32 (reward) + 32 (asset) + 32 (owner) + 8 (cost) + 4 (serial)
+    /// + 8 (redeemed_at) + 8 (fulfilled_at) + 1 (bump) + 1 (version) + 32 (reserved) = 166
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 4 + 8 + 8 + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::protocol_config::ProtocolConfig;
 
 /// On-chain XP ledger for each AETERNA Pass.
 /// Seeds: ["soul_stats", asset.key()]
@@ -13,18 +14,81 @@ pub struct SoulStats {
     /// Current evolution stage (0 = Dormant, 1 = Active, 2 = Ascended)
     /// Stored here so the program can enforce stage logic without reading Metaplex attributes
     pub current_stage: u8,
-    /// Total DeFi volume generated via Pulse swaps
+    /// Total DeFi volume generated via Pulse, in volume units (`aeterna_rules::wealth::VOLUME_DECIMALS`)
     pub trading_volume: u64,
     /// Canonical bump for this PDA
     pub bump: u8,
+    /// Account layout version (0 = legacy 62-byte layout)
+    pub version: u8,
+    /// 1-indexed level from the `ProtocolConfig` ladder (0 = not yet computed)
+    pub level: u8,
+    /// Wealth tier derived from `trading_volume` (0 = Common, 1 = Gold, 2 = Diamond)
+    pub wealth_tier: u8,
+    /// Spendable points, earned 1:1 with XP and spent via `redeem_reward`
+    pub points: u64,
+    /// Start of the current fixed 24h XP window (Unix seconds)
+    pub xp_window_start: i64,
+    /// XP credited from any source so far in the current window
+    pub xp_window_granted: u64,
+    /// Zeroed space for future fields — new fields are carved from here so
+    /// existing accounts stay readable with a zero default
+    pub reserved: [u8; 102],
 }
 
 impl SoulStats {
-    /// 8 (discriminator) + 32 (asset) + 8 (xp) + 4 (quests_completed) + 1 (current_stage) + 8 (trading_volume) + 1 (bump) = 62
-    pub const SIZE: usize = 8 + 32 + 8 + 4 + 1 + 8 + 1;
+    /// 8 (discriminator) + 32 (asset) + 8 (xp) + 4 (quests_completed) + 1 (current_stage) + 8 (trading_volume) + 1 (bump)
+    /// + 1 (version) + 1 (level) + 1 (wealth_tier) + 8 (points) + 8 (xp_window_start) + 8 (xp_window_granted)
+    /// + 102 (reserved) = 191
+    pub const SIZE: usize = 8 + 32 + 8 + 4 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 8 + 8 + 102;
+    /// Current layout version written on init and by `migrate_account`
+    pub const VERSION: u8 = 1;
 
     /// XP required to reach ACTIVE state (stage 1)
-    pub const THRESHOLD_ACTIVE: u64 = 100;
+    pub const THRESHOLD_ACTIVE: u64 = aeterna_rules::stage::THRESHOLD_ACTIVE;
     /// XP required to reach ASCENDED state (stage 2)
-    pub const THRESHOLD_ASCENDED: u64 = 1000;
+    pub const THRESHOLD_ASCENDED: u64 = aeterna_rules::stage::THRESHOLD_ASCENDED;
+
+    pub const WEALTH_COMMON: u8 = aeterna_rules::wealth::COMMON;
+    pub const WEALTH_GOLD: u8 = aeterna_rules::wealth::GOLD;
+    pub const WEALTH_DIAMOND: u8 = aeterna_rules::wealth::DIAMOND;
+
+    pub const GRANT_WINDOW: i64 = aeterna_rules::window::LENGTH;
+
+    /// Credit earned XP and the matching spendable points
+    pub fn earn(&mut self, amount: u64) {
+        self.xp = self.xp.saturating_add(amount);
+        self.points = self.points.saturating_add(amount);
+    }
+
+    /// Check an XP credit of `amount` against the config's per-grant and daily caps and record it.
+    /// Every XP source calls this before `earn`. The window is fixed (`aeterna_rules::window`)
+    /// and a `daily_xp_cap` of 0 means uncapped.
+    pub fn record_grant(&mut self, amount: u64, config: &ProtocolConfig, now: i64) -> Result<()> {
+        require!(
+            config.max_grant_xp == 0 || amount <= config.max_grant_xp,
+            crate::AeternaError::GrantTooLarge
+        );
+
+        let cap = (config.daily_xp_cap != 0).then_some(config.daily_xp_cap);
+        let (start, granted) =
+            aeterna_rules::window::record(self.xp_window_start, self.xp_window_granted, amount, cap, now)
+                .ok_or(crate::AeternaError::DailyXpCapExceeded)?;
+        self.xp_window_start = start;
+        self.xp_window_granted = granted;
+        Ok(())
+    }
+
+    /// Recompute `level` from current XP. Returns the previous level if it changed.
+    pub fn refresh_level(&mut self, config: &ProtocolConfig) -> Option<u8> {
+        let previous = self.level;
+        self.level = config.level_for(self.xp);
+        (self.level != previous).then_some(previous)
+    }
+
+    /// Recompute `wealth_tier` from trading volume. Returns the previous tier if it changed.
+    pub fn refresh_wealth_tier(&mut self, config: &ProtocolConfig) -> Option<u8> {
+        let previous = self.wealth_tier;
+        self.wealth_tier = config.wealth_tier_for(self.trading_volume);
+        (self.wealth_tier != previous).then_some(previous)
+    }
 }
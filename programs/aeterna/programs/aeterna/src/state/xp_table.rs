@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// XP and volume weight for one backend-reported action type (swap, mint, vote, ...)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct XpTableEntry {
+    /// Action type id passed to `grant_xp` and referenced by `ActionType::xp_action`
+    pub action: u8,
+    /// Display name (e.g. "SWAP")
+    pub name: String,
+    /// XP credited per action
+    pub xp_per_action: u64,
+    /// Most occurrences a single `grant_xp` may report (must be non-zero)
+    pub max_count: u32,
+    /// Share of the grant's `add_trading_volume` that counts towards the soul's trading volume
+    /// (10_000 = all of it)
+    pub volume_weight_bps: u16,
+}
+
+/// Protocol-wide XP values for `grant_xp` and `claim_action_xp`, so the backend reports what
+/// happened rather than how much XP it is worth. Updated by the protocol admin without a deploy.
+/// Seeds: ["xp_table"]
+#[account]
+pub struct XpTable {
+    pub entries: Vec<XpTableEntry>,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Zeroed space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl XpTable {
+    pub const MAX_ENTRIES: usize = 16;
+    pub const MAX_NAME_LEN: usize = 16;
+    pub const MAX_VOLUME_WEIGHT_BPS: u16 = 10_000;
+
+    /// 8 (discriminator) + 4 + 16 * (1 + (4 + 16) + 8 + 4 + 2) (entries) + 1 (bump) + 1 (version)
+    /// + 32 (reserved) = 606
+    pub const SIZE: usize = 8 + 4 + Self::MAX_ENTRIES * (1 + (4 + Self::MAX_NAME_LEN) + 8 + 4 + 2) + 1 + 1 + 32;
+    pub const VERSION: u8 = 1;
+
+    pub fn entry(&self, action: u8) -> Option<&XpTableEntry> {
+        self.entries.iter().find(|entry| entry.action == action)
+    }
+}
+
+impl XpTableEntry {
+    /// Whether one grant may report `count` occurrences of this action
+    pub fn allows_count(&self, count: u32) -> bool {
+        count > 0 && count <= self.max_count
+    }
+
+    /// XP for `count` occurrences of this action, saturating at `u64::MAX`
+    pub fn xp_for(&self, count: u32) -> u64 {
+        self.xp_per_action.saturating_mul(count as u64)
+    }
+
+    /// The part of `volume` that counts towards trading volume, rounded down
+    pub fn weighted_volume(&self, volume: u64) -> u64 {
+        (volume as u128 * self.volume_weight_bps as u128 / aeterna_rules::xp::BPS_BASE as u128) as u64
+    }
+}
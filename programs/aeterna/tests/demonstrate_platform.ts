@@ -34,6 +34,45 @@ describe("Aeterna Platform Demo", () => {
         );
     });
 
+    const CORE_PROGRAM_ID = new anchor.web3.PublicKey("CoREENxT6tW1HoY8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+    const pda = (...seeds: Buffer[]) =>
+        anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+    const configPda = pda(Buffer.from("config"));
+    const xpTablePda = pda(Buffer.from("xp_table"));
+
+    // --- STEP 0: PROTOCOL CONFIG ---
+    // `initialize_config` is gated on BACKEND_AUTHORITY, so the provider wallet must be that key.
+    // Minting, quests and grants all read the config, so it has to exist first.
+    it("Initializes Protocol Config (Admin Layer)", async () => {
+        console.log("\n0️⃣  Initializing Protocol Config...");
+        const admin = provider.wallet.publicKey;
+
+        try {
+            await program.methods
+                .initializeConfig([{ minXp: new anchor.BN(0), title: "Dormant Soul" }])
+                .accounts({
+                    authority: admin,
+                    config: configPda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Config initialized at ${configPda.toBase58()}`);
+        } catch (e) { console.log("Config likely already exists"); }
+
+        try {
+            await program.methods
+                .initializeXpTable([{ action: 0, name: "SCAN", xpPerAction: new anchor.BN(100), maxCount: 10, volumeWeightBps: 0 }])
+                .accounts({
+                    authority: admin,
+                    config: configPda,
+                    xpTable: xpTablePda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ XP table initialized at ${xpTablePda.toBase58()}`);
+        } catch (e) { console.log("XP table likely already exists"); }
+    });
+
     // --- STEP 1: CREATE EVENTS ---
     it("Creates Events (Platform Layer)", async () => {
         console.log("\n1️⃣  Creating Events...");
@@ -87,7 +126,7 @@ describe("Aeterna Platform Demo", () => {
         // User buys a Techno Blast ticket
         const aliceTicket = Keypair.generate();
         await program.methods
-            .initializePass({ uri: "https://arweave.net/techno-ticket-metadata" })
+            .initializePass({ uri: "https://arweave.net/techno-ticket-metadata", inviteCode: "AETERNA_GENESIS" })
             .accounts({
                 signer: user.publicKey,
                 authority: alice.publicKey, // Alice is the authority for her tickets
@@ -95,7 +134,10 @@ describe("Aeterna Platform Demo", () => {
                 asset: aliceTicket.publicKey,
                 event: aliceEventPda, // Linked to Alice's Event
                 collection: null,
-                mplCoreProgram: new anchor.web3.PublicKey("CoREENxT6tW1HoY8ypY1SxRMZTcVPm7R94rH4PZNhX7d"),
+                mplCoreProgram: CORE_PROGRAM_ID,
+                soulStats: pda(Buffer.from("soul_stats"), aliceTicket.publicKey.toBuffer()),
+                participation: pda(Buffer.from("participation"), aliceEventPda.toBuffer(), aliceTicket.publicKey.toBuffer()),
+                config: configPda,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([user, alice, aliceTicket])
@@ -187,7 +229,7 @@ describe("Aeterna Platform Demo", () => {
 
         try {
             await program.methods
-                .createQuest("Main Stage", new anchor.BN(50))
+                .createQuest("Main Stage", new anchor.BN(50), 0) // open to every wealth tier
                 .accounts({
                     authority: alice.publicKey,
                     event: aliceEventPda,
@@ -199,7 +241,43 @@ describe("Aeterna Platform Demo", () => {
             console.log(`✅ Alice created Quest "Main Stage" (50 XP)`);
         } catch (e) { console.log("Quest likely exists"); }
 
-        // 2. User Completes Quest
+        // 2. Dormant souls cannot earn quest XP: grant the entry scan and evolve to Active first
+        const soulStatsPda = pda(Buffer.from("soul_stats"), aliceTicketKey.toBuffer());
+        const receiptId = Array.from(Keypair.generate().publicKey.toBytes());
+        await program.methods
+            .grantXp({ receiptId, action: 0, count: 1, addTradingVolume: null, questsCompleted: null })
+            .accounts({
+                authority: provider.wallet.publicKey,
+                soulStats: soulStatsPda,
+                config: configPda,
+                xpTable: xpTablePda,
+                asset: aliceTicketKey,
+                mplCoreProgram: CORE_PROGRAM_ID,
+                participation: null,
+                leaderboard: null,
+                merchant: null,
+                boost: null,
+                receipt: pda(Buffer.from("grant_receipt"), Buffer.from(receiptId)),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .evolveSoul({ newUri: null, newStage: 1, attributes: [] })
+            .accounts({
+                authority: alice.publicKey,
+                payer: alice.publicKey,
+                asset: aliceTicketKey,
+                soulStats: soulStatsPda,
+                config: configPda,
+                mplCoreProgram: CORE_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([alice])
+            .rpc();
+        console.log(`✅ Soul evolved to Active`);
+
+        // 3. User Completes Quest
         await program.methods
             .completeQuest()
             .accounts({
@@ -208,7 +286,14 @@ describe("Aeterna Platform Demo", () => {
                 quest: questPda,
                 event: aliceEventPda,
                 asset: aliceTicketKey,
-                mplCoreProgram: new anchor.web3.PublicKey("CoREENxT6tW1HoY8ypY1SxRMZTcVPm7R94rH4PZNhX7d"),
+                recipient: user.publicKey,
+                soulStats: soulStatsPda,
+                config: configPda,
+                completionRecord: pda(Buffer.from("completion"), questPda.toBuffer(), aliceTicketKey.toBuffer()),
+                participation: pda(Buffer.from("participation"), aliceEventPda.toBuffer(), aliceTicketKey.toBuffer()),
+                leaderboard: pda(Buffer.from("leaderboard"), aliceEventPda.toBuffer()),
+                boost: null,
+                mplCoreProgram: CORE_PROGRAM_ID,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([alice])